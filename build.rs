@@ -64,7 +64,11 @@ fn main() {
                 .map(|field| {
                     let name = &field.ident;
 
-                    let l_name = name.as_ref().unwrap().to_string().to_snake_case();
+                    let l_name = name.as_ref().unwrap().to_string();
+                    let l_name = match l_name.strip_prefix("r#") {
+                        Some(raw) if raw != "type" => raw.to_snake_case(),
+                        _ => l_name.to_snake_case(),
+                    };
                     let set_name =
                         &if l_name.eq("r_type") {
                             format!(
@@ -84,7 +88,11 @@ fn main() {
                         &format!("get_{set_name}"),
                         proc_macro2::Span::call_site(),
                     );
-                    let set_name = ident(set_name).unwrap();
+                    // other raw identifiers, eg. `r#loop`, keep the raw setter name
+                    let set_name = match name.as_ref().unwrap().to_string().strip_prefix("r#") {
+                        Some(raw) if raw != "type" => ident(&format!("r#{raw}")).unwrap(),
+                        _ => ident(set_name).unwrap(),
+                    };
 
                     let type_ = field.ty.clone();
 
@@ -218,8 +226,8 @@ fn override_set_fn(s: &ItemStruct, field: &Field) -> Option<TokenStream> {
         }
 
         if seg == "Option" {
-            // for Option<HashMap<T, U>>
-            if type_segments[1].0 == "HashMap" {
+            // for Option<HashMap<T, U>> and Option<BTreeMap<T, U>>
+            if ["HashMap", "BTreeMap"].contains(&type_segments[1].0.as_str()) {
                 let inner_t = &type_segments[2].1;
                 let inner_u = &type_segments[3].1;
                 let iterator_set_fn = quote! {
@@ -243,6 +251,18 @@ fn override_set_fn(s: &ItemStruct, field: &Field) -> Option<TokenStream> {
                 return Some(iterator_set_fn);
             }
 
+            // for Option<FnWithArgsOrT<N, T>>, the const generic is lost when splitting the type string
+            if type_segments[1].0 == "FnWithArgsOrT" {
+                let inner_t = option_inner_type(type_)?;
+
+                return Some(quote! {
+                    pub fn #set_name(mut self, value: impl Into<#inner_t>) -> #s_name #type_params {
+                        self.#name = Some(value.into());
+                        self
+                    }
+                });
+            }
+
             if type_segments[1].0 == "u32" {
                 let type_ = &type_segments[1].1;
 
@@ -309,6 +329,20 @@ fn type_segments(type_: &syn::Type) -> (Vec<(String, syn::Type)>, Vec<String>) {
     (segs, seps)
 }
 
+fn option_inner_type(type_: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(path) = type_ else {
+        return None;
+    };
+    let syn::PathArguments::AngleBracketed(args) = &path.path.segments.last()?.arguments else {
+        return None;
+    };
+
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(t) => Some(t),
+        _ => None,
+    })
+}
+
 fn ident(i: &str) -> Result<syn::Type, syn::Error> {
     syn::parse_str(i)
 }
@@ -8,6 +8,98 @@ const chartAreaBackground = {
         ctx.restore();
     }
 };
+const crosshair = {
+    id: 'crosshair',
+    defaults: {
+        badgeBackgroundColor: 'rgba(0, 0, 0, 0.75)',
+        badgeColor: 'white',
+        badges: true,
+        color: 'rgba(0, 0, 0, 0.4)',
+        dash: [],
+        font: { size: 11 },
+        horizontal: false,
+        snap: false,
+        vertical: true,
+        width: 1,
+        xScaleID: 'x',
+        yScaleID: 'y',
+    },
+    // stores the hovered position on the chart, or clears it when the pointer leaves the chart area
+    move: (chart, event, options) => {
+        const { left, right, top, bottom } = chart.chartArea;
+        if (!event || event.type === 'mouseout' || event.type === 'mouseleave'
+            || event.x < left || event.x > right || event.y < top || event.y > bottom) {
+            chart.crosshair = undefined;
+            return;
+        }
+
+        let { x, y } = event;
+        if (options.snap) {
+            const [active] = chart.getElementsAtEventForMode(event, 'index', { intersect: false }, false);
+            if (active) x = active.element.x;
+        }
+        chart.crosshair = { x, y };
+    },
+    afterEvent: (chart, args, options) => {
+        const previous = chart.crosshair;
+        crosshair.move(chart, args.event, options);
+        const current = chart.crosshair;
+        // only redraw when the crosshair moved, appeared or was cleared
+        if (previous?.x !== current?.x || previous?.y !== current?.y) {
+            args.changed = true;
+        }
+    },
+    afterDraw: (chart, args, options) => {
+        if (!chart.crosshair) return;
+        const { x, y } = chart.crosshair;
+        const { ctx, chartArea: { left, right, top, bottom } } = chart;
+
+        ctx.save();
+        ctx.lineWidth = options.width;
+        ctx.strokeStyle = options.color;
+        ctx.setLineDash(options.dash);
+        ctx.beginPath();
+        if (options.vertical) {
+            ctx.moveTo(x, top);
+            ctx.lineTo(x, bottom);
+        }
+        if (options.horizontal) {
+            ctx.moveTo(left, y);
+            ctx.lineTo(right, y);
+        }
+        ctx.stroke();
+        ctx.setLineDash([]);
+
+        if (options.badges) {
+            const font = Chart.helpers.toFont(options.font);
+            ctx.font = font.string;
+            ctx.textAlign = 'center';
+            ctx.textBaseline = 'middle';
+
+            // draws `text` in a box `width` wide, centred on (cx, cy)
+            const badge = (text, cx, cy, width = ctx.measureText(text).width + 8) => {
+                const height = font.lineHeight + 4;
+                ctx.fillStyle = options.badgeBackgroundColor;
+                ctx.fillRect(cx - width / 2, cy - height / 2, width, height);
+                ctx.fillStyle = options.badgeColor;
+                ctx.fillText(text, cx, cy);
+            };
+            const label = (scale, pixel) => `${scale.getLabelForValue(scale.getValueForPixel(pixel))}`;
+
+            const xScale = chart.scales[options.xScaleID];
+            if (options.vertical && xScale) {
+                badge(label(xScale, x), x, bottom + (font.lineHeight + 4) / 2);
+            }
+            const yScale = chart.scales[options.yScaleID];
+            if (options.horizontal && yScale) {
+                const text = label(yScale, y);
+                const width = ctx.measureText(text).width + 8;
+                badge(text, left - width / 2, y, width);
+            }
+        }
+        ctx.restore();
+    }
+};
 export function get_chart(id) {
     return Chart.getChart(document.getElementById(id)).config._config
 }
@@ -34,11 +126,34 @@ export function render_chart(v, id, mutate, plugins, defaults) {
 
     console.debug('After mutate:', obj);
 
+    // the built in crosshair plugin is added to charts that configure it
+    if (obj.options?.plugins?.crosshair && !(obj.plugins ?? []).includes(crosshair)) {
+        obj.plugins = [...(obj.plugins ?? []), crosshair];
+    }
+
     const ctx = document.getElementById(id);
     let chart = new Chart(ctx, obj);
 }
 
-export function update_chart(updated, id, animate) {
+// animates one update with `transition`, then restores the chart's own animation options,
+// the default mode merges it into the options, other modes use `options.transitions[mode]`
+function update_with_transition(chart, mode, transition) {
+    if (!transition) {
+        chart.update(mode);
+        return;
+    }
+    const options = chart.config._config.options ??= {};
+    const target = mode === undefined ? options : (options.transitions ??= {});
+    const entries = mode === undefined ? Object.entries(transition) : [[mode, transition]];
+    const previous = entries.map(([key]) => [key, Object.hasOwn(target, key), target[key]]);
+    entries.forEach(([key, value]) => target[key] = value);
+    try {
+        chart.update(mode);
+    } finally {
+        previous.forEach(([key, had, value]) => had ? target[key] = value : delete target[key]);
+    }
+}
+export function update_chart(updated, id, mode, transition) {
     try {
         let chart = Chart.getChart(document.getElementById(id));
         chart.config._config.type = updated.type;
@@ -47,15 +162,32 @@ export function update_chart(updated, id, animate) {
 
         console.debug('Updated chart:', chart);
 
-        if (animate) {
-            chart.update();
-        } else {
-            chart.update('none');
-        }
+        // `undefined` is the default update, animated with `options.animation`
+        update_with_transition(chart, mode, transition);
 
         true
     }
     catch {
         false
     }
-} 
\ No newline at end of file
+} 
+
+export function reset_zoom(id) {
+    try {
+        Chart.getChart(document.getElementById(id)).resetZoom();
+        return true
+    }
+    catch {
+        return false
+    }
+}
+
+export function zoom_scale(id, scaleId, min, max, mode) {
+    try {
+        Chart.getChart(document.getElementById(id)).zoomScale(scaleId, { min, max }, mode);
+        return true
+    }
+    catch {
+        return false
+    }
+}
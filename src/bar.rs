@@ -37,6 +37,14 @@ impl<D: DatasetTrait> ChartExt for Bar<D> {
     }
 }
 
+impl Bar<Vec<XYDataset>> {
+    /// Stacks the bars, stacking both the index and value scales the datasets are bound to
+    pub fn stacked(mut self, stacking: crate::stacking::Stacking) -> Self {
+        stacking.apply(self.data.get_datasets(), &mut self.options, true);
+        self
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct BarString;
 impl<'de> Deserialize<'de> for BarString {
@@ -1,10 +1,19 @@
+//! Parsing the values Chart.js passes to callbacks, eg. the coordinates of tooltip data points
+//! and the events and contexts passed to the `*_closure` methods.
+//!
+//! Every event and context has a `chart_id`, the id of the chart's canvas. Events also have the
+//! native event's `event_type`, eg. `click` or `mousemove`, and its `x` and `y` relative to the canvas.
+//! Parsing returns a [`CoordinateError`] when Chart.js passes something unexpected.
+
 #![allow(dead_code)]
 
 use {
+    crate::utils::canvas_id,
     gloo_utils::format::JsValueSerdeExt,
-    js_sys::{Array, Reflect},
+    js_sys::{Array, Object, Reflect},
     std::{
         any::type_name,
+        collections::HashMap,
         error::Error,
         fmt::{self, Display},
         str::FromStr,
@@ -316,3 +325,531 @@ pub struct ChartJsPointDataset {
     pub label: String,
     pub r#type: String,
 }
+
+/// A chart event passed to [`crate::objects::ChartOptions::on_click_closure`] and
+/// [`crate::objects::ChartOptions::on_hover_closure`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ChartEvent<T, U> {
+    pub chart_id: String,
+    pub event_type: String,
+    pub x: f64,
+    pub y: f64,
+    /// The elements under the event, for the chart's `interaction` mode
+    pub elements: Vec<ActiveElement<T, U>>,
+}
+
+/// An element under a [`ChartEvent`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ActiveElement<T, U> {
+    pub dataset_index: usize,
+    pub index: usize,
+    /// The element's parsed data point, on a category scale `x` is the matching label
+    pub coordinate: Coordinate<T, U>,
+}
+
+impl<T, TE, U, UE> ChartEvent<T, U>
+where
+    T: FromStr<Err = TE>,
+    U: FromStr<Err = UE>,
+    TE: std::error::Error + Sync + Send + 'static,
+    UE: std::error::Error + Sync + Send + 'static,
+{
+    /// Parses the `(event, elements, chart)` arguments of Chart.js' `onClick` and `onHover`
+    pub fn parse(
+        event: JsValue,
+        elements: JsValue,
+        chart: JsValue,
+    ) -> Result<Self, CoordinateError> {
+        let elements = Array::from(&elements)
+            .iter()
+            .map(|element| {
+                let dataset_index = get_f64(&element, "datasetIndex")? as usize;
+                let index = get_f64(&element, "index")? as usize;
+                Ok(ActiveElement {
+                    dataset_index,
+                    index,
+                    coordinate: element_coordinate(&chart, dataset_index, index)?,
+                })
+            })
+            .collect::<Result<Vec<_>, CoordinateError>>()?;
+
+        Ok(ChartEvent {
+            chart_id: canvas_id(&chart).unwrap_or_default(),
+            event_type: get_string(&event, "type")?,
+            x: get_f64(&event, "x")?,
+            y: get_f64(&event, "y")?,
+            elements,
+        })
+    }
+}
+
+/// Reads the parsed value of `chart.data.datasets[dataset_index].data[index]` from the dataset's meta,
+/// so `parsing` keys and bare values work. On a category scale `x` is the label, otherwise the parsed number,
+/// eg. milliseconds for a time scale.
+fn element_coordinate<T, TE, U, UE>(
+    chart: &JsValue,
+    dataset_index: usize,
+    index: usize,
+) -> Result<Coordinate<T, U>, CoordinateError>
+where
+    T: FromStr<Err = TE>,
+    U: FromStr<Err = UE>,
+    TE: std::error::Error + Sync + Send + 'static,
+    UE: std::error::Error + Sync + Send + 'static,
+{
+    let meta = call_method(chart, "getDatasetMeta", &(dataset_index as u32).into())?;
+    let parsed = Reflect::get(&get_field(&meta, "_parsed")?, &(index as u32).into())
+        .ok()
+        .filter(|parsed| parsed.is_object())
+        .ok_or_else(|| CoordinateError::MissingKey {
+            value: meta.clone(),
+            key: format!("_parsed[{index}]"),
+        })?;
+
+    let x = get_f64(&parsed, "x")?;
+    let x_scale = get_field(&meta, "xScale")?;
+    let x = match get_field(&x_scale, "type")?.as_string().as_deref() {
+        Some("category") => {
+            let label = Reflect::get(
+                &call_method(&x_scale, "getLabels", &JsValue::UNDEFINED)?,
+                &(x as u32).into(),
+            )
+            .unwrap_or_default();
+            label
+                .as_string()
+                .or_else(|| label.as_f64().map(|x| x.to_string()))
+                .unwrap_or_default()
+        }
+        _ => x.to_string(),
+    };
+    let y = serde_json::Number::from_f64(get_f64(&parsed, "y")?).ok_or_else(|| {
+        CoordinateError::GetTypedKey {
+            value: parsed.clone(),
+            key: "y".into(),
+            ty: "f64",
+        }
+    })?;
+
+    Coordinate::from_raw(Coordinate_ { x, y })
+}
+
+fn call_method(val: &JsValue, method: &str, arg: &JsValue) -> Result<JsValue, CoordinateError> {
+    let missing = || CoordinateError::MissingKey {
+        value: val.clone(),
+        key: format!("{method}()"),
+    };
+    get_field(val, method)?
+        .dyn_into::<js_sys::Function>()
+        .map_err(|_| missing())?
+        .call1(val, arg)
+        .map_err(|_| missing())
+}
+
+/// A legend event passed to [`crate::objects::PluginLegend::on_click_closure`],
+/// [`crate::objects::PluginLegend::on_hover_closure`] and [`crate::objects::PluginLegend::on_leave_closure`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct LegendEvent {
+    pub chart_id: String,
+    pub event_type: String,
+    pub x: f64,
+    pub y: f64,
+    pub item: LegendItem,
+    chart: JsValue,
+}
+
+/// The legend item under a [`LegendEvent`]
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct LegendItem {
+    pub text: String,
+    pub hidden: bool,
+    /// Set for charts with a legend item per dataset
+    pub dataset_index: Option<usize>,
+    /// Set for charts with a legend item per data point, eg. pie and doughnut charts
+    pub index: Option<usize>,
+}
+
+impl LegendEvent {
+    /// Parses the `(event, legendItem, legend)` arguments of Chart.js' legend `onClick`, `onHover` and `onLeave`
+    pub fn parse(event: JsValue, item: JsValue, legend: JsValue) -> Result<Self, CoordinateError> {
+        let chart = get_field(&legend, "chart")?;
+        Ok(LegendEvent {
+            chart_id: canvas_id(&chart).unwrap_or_default(),
+            event_type: get_string(&event, "type")?,
+            x: get_f64(&event, "x")?,
+            y: get_f64(&event, "y")?,
+            item: LegendItem {
+                text: get_string(&item, "text").unwrap_or_default(),
+                hidden: get_field(&item, "hidden")?.is_truthy(),
+                dataset_index: get_f64(&item, "datasetIndex").ok().map(|i| i as usize),
+                index: get_f64(&item, "index").ok().map(|i| i as usize),
+            },
+            chart,
+        })
+    }
+
+    /// Shows or hides the clicked item, the default Chart.js behaviour
+    pub fn toggle(&self) {
+        if let Some(dataset_index) = self.item.dataset_index {
+            let visible = self
+                .call("isDatasetVisible", [dataset_index.into()])
+                .is_truthy();
+            self.call(
+                "setDatasetVisibility",
+                [dataset_index.into(), (!visible).into()],
+            );
+        } else if let Some(index) = self.item.index {
+            self.call("toggleDataVisibility", [index.into()]);
+        }
+        self.call("update", []);
+    }
+
+    /// Shows only the clicked item, hiding every other dataset or data point
+    pub fn isolate(&self) {
+        let data = get_field(&self.chart, "data").unwrap_or_default();
+        if let Some(dataset_index) = self.item.dataset_index {
+            let datasets = Array::from(&get_field(&data, "datasets").unwrap_or_default());
+            for idx in 0..datasets.length() as usize {
+                self.call(
+                    "setDatasetVisibility",
+                    [idx.into(), (idx == dataset_index).into()],
+                );
+            }
+        } else if let Some(index) = self.item.index {
+            let labels = Array::from(&get_field(&data, "labels").unwrap_or_default());
+            for idx in 0..labels.length() as usize {
+                let visible = self.call("getDataVisibility", [idx.into()]).is_truthy();
+                if visible != (idx == index) {
+                    self.call("toggleDataVisibility", [idx.into()]);
+                }
+            }
+        }
+        self.call("update", []);
+    }
+
+    fn call<const N: usize>(&self, method: &str, args: [JsValue; N]) -> JsValue {
+        get_field(&self.chart, method)
+            .ok()
+            .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+            .and_then(|f| {
+                f.apply(&self.chart, &args.into_iter().collect::<Array>())
+                    .ok()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// An annotation event passed to the `on_click_closure`, `on_enter_closure` and `on_leave_closure`
+/// methods of the annotation types, eg. [`crate::objects::BoxAnnotation::on_click_closure`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct AnnotationEvent {
+    pub chart_id: String,
+    /// The key of the annotation in [`crate::objects::Annotations`]
+    pub annotation_id: String,
+    pub event_type: String,
+    pub x: f64,
+    pub y: f64,
+}
+
+impl AnnotationEvent {
+    /// Parses the `(context, event)` arguments of chartjs-plugin-annotation's `click`, `enter` and `leave`
+    pub fn parse(context: JsValue, event: JsValue) -> Result<Self, CoordinateError> {
+        let chart = get_field(&context, "chart")?;
+        Ok(AnnotationEvent {
+            chart_id: canvas_id(&chart).unwrap_or_default(),
+            annotation_id: get_string(&context, "id").unwrap_or_default(),
+            event_type: get_string(&event, "type")?,
+            x: get_f64(&event, "x")?,
+            y: get_f64(&event, "y")?,
+        })
+    }
+}
+
+/// A label event passed to the closures of [`crate::objects::DataLabelsListeners`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct DataLabelEvent {
+    pub chart_id: String,
+    pub dataset_index: usize,
+    /// The index of the data point in the dataset
+    pub index: usize,
+    pub event_type: String,
+    pub x: f64,
+    pub y: f64,
+}
+
+impl DataLabelEvent {
+    /// Parses the `(context, event)` arguments of chartjs-plugin-datalabels' `click`, `enter` and `leave` listeners
+    pub fn parse(context: JsValue, event: JsValue) -> Result<Self, CoordinateError> {
+        let chart = get_field(&context, "chart")?;
+        Ok(DataLabelEvent {
+            chart_id: canvas_id(&chart).unwrap_or_default(),
+            dataset_index: get_f64(&context, "datasetIndex")? as usize,
+            index: get_f64(&context, "dataIndex")? as usize,
+            event_type: get_string(&event, "type")?,
+            x: get_f64(&event, "x")?,
+            y: get_f64(&event, "y")?,
+        })
+    }
+}
+
+/// The new size passed to [`crate::objects::ChartOptions::on_resize_closure`]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ChartResize {
+    pub chart_id: String,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl ChartResize {
+    /// Parses the `(chart, size)` arguments of Chart.js' `onResize`
+    pub fn parse(chart: JsValue, size: JsValue) -> Result<Self, CoordinateError> {
+        Ok(ChartResize {
+            chart_id: canvas_id(&chart).unwrap_or_default(),
+            width: get_f64(&size, "width")?,
+            height: get_f64(&size, "height")?,
+        })
+    }
+}
+
+/// The state passed to [`crate::objects::Animation::on_progress_closure`] and
+/// [`crate::objects::Animation::on_complete_closure`]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct AnimationProgress {
+    pub chart_id: String,
+    pub current_step: f64,
+    pub num_steps: f64,
+    /// `true` for the chart's first render
+    pub initial: bool,
+}
+
+impl AnimationProgress {
+    /// Parses the `animation` argument of Chart.js' `animation.onProgress` and `animation.onComplete`
+    pub fn parse(animation: JsValue) -> Result<Self, CoordinateError> {
+        Ok(AnimationProgress {
+            chart_id: canvas_id(&get_field(&animation, "chart")?).unwrap_or_default(),
+            current_step: get_f64(&animation, "currentStep")?,
+            num_steps: get_f64(&animation, "numSteps")?,
+            initial: get_field(&animation, "initial")?.is_truthy(),
+        })
+    }
+
+    /// How far through the animation this frame is, from `0.0` to `1.0`
+    pub fn progress(&self) -> f64 {
+        if self.num_steps == 0.0 {
+            1.0
+        } else {
+            self.current_step / self.num_steps
+        }
+    }
+}
+
+/// The visible range of each scale, passed to [`crate::objects::ZoomOptions::on_zoom_complete_closure`]
+/// and [`crate::objects::PanOptions::on_pan_complete_closure`]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ScaleRanges {
+    pub chart_id: String,
+    /// Keyed by scale id, time scales are in milliseconds since the Unix epoch
+    pub scales: HashMap<String, ScaleRange>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScaleRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ScaleRanges {
+    /// Parses the `context` argument of chartjs-plugin-zoom's `onZoomComplete` and `onPanComplete`
+    pub fn parse(context: JsValue) -> Result<Self, CoordinateError> {
+        let chart = get_field(&context, "chart")?;
+        let scales = get_field(&chart, "scales")?;
+        let scales = Object::entries(scales.dyn_ref::<Object>().ok_or_else(|| {
+            CoordinateError::GetTypedKey {
+                value: chart.clone(),
+                key: "scales".into(),
+                ty: "Object",
+            }
+        })?)
+        .iter()
+        .map(|entry| {
+            let entry = Array::from(&entry);
+            let scale = entry.get(1);
+            Ok((
+                entry.get(0).as_string().unwrap_or_default(),
+                ScaleRange {
+                    min: get_f64(&scale, "min")?,
+                    max: get_f64(&scale, "max")?,
+                },
+            ))
+        })
+        .collect::<Result<_, CoordinateError>>()?;
+
+        Ok(ScaleRanges {
+            chart_id: canvas_id(&chart).unwrap_or_default(),
+            scales,
+        })
+    }
+}
+
+/// The scriptable context passed to [`crate::objects::LabelAnnotation::content_closure`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AnnotationContext {
+    pub chart_id: String,
+    /// The key of the annotation in [`crate::objects::Annotations`]
+    pub annotation_id: String,
+}
+
+impl AnnotationContext {
+    /// Parses the `context` argument of chartjs-plugin-annotation's scriptable options
+    pub fn parse(context: JsValue) -> Result<Self, CoordinateError> {
+        Ok(AnnotationContext {
+            chart_id: canvas_id(&get_field(&context, "chart")?).unwrap_or_default(),
+            annotation_id: get_string(&context, "id").unwrap_or_default(),
+        })
+    }
+}
+
+/// The tooltip state passed to [`crate::objects::TooltipPlugin::external_html`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TooltipModel<T, U> {
+    pub chart_id: String,
+    /// `0.0` when the tooltip should be hidden
+    pub opacity: f64,
+    /// The caret position, relative to the canvas
+    pub caret_x: f64,
+    /// The caret position, relative to the canvas
+    pub caret_y: f64,
+    /// The top left of the canvas tooltip, relative to the canvas
+    pub x: f64,
+    /// The top left of the canvas tooltip, relative to the canvas
+    pub y: f64,
+    pub x_align: String,
+    pub y_align: String,
+    pub title: Vec<String>,
+    pub before_body: Vec<String>,
+    pub body: Vec<TooltipBodyItem>,
+    pub after_body: Vec<String>,
+    pub footer: Vec<String>,
+    pub data_points: Vec<ChartJsPoint<T, U>>,
+    /// The colour box of each body item
+    pub label_colors: Vec<TooltipLabelColor>,
+}
+
+/// The lines of one item in a [`TooltipModel`]
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct TooltipBodyItem {
+    pub before: Vec<String>,
+    pub lines: Vec<String>,
+    pub after: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct TooltipLabelColor {
+    pub border_color: String,
+    pub background_color: String,
+}
+
+fn get_strings(val: &JsValue, key: &str) -> Vec<String> {
+    get_field(val, key)
+        .map(|v| {
+            Array::from(&v)
+                .iter()
+                .filter_map(|s| s.as_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl<T, TE, U, UE> TooltipModel<T, U>
+where
+    T: FromStr<Err = TE>,
+    U: FromStr<Err = UE>,
+    TE: std::error::Error + Sync + Send + 'static,
+    UE: std::error::Error + Sync + Send + 'static,
+    T: fmt::Debug,
+    U: fmt::Debug,
+{
+    /// Parses the `context` argument of Chart.js' `tooltip.external`
+    pub fn parse(context: &JsValue) -> Result<Self, CoordinateError> {
+        let chart = get_field(context, "chart")?;
+        let tooltip = get_field(context, "tooltip")?;
+        let data_points = get_field(&tooltip, "dataPoints")?;
+
+        Ok(TooltipModel {
+            chart_id: canvas_id(&chart).unwrap_or_default(),
+            opacity: get_f64(&tooltip, "opacity")?,
+            caret_x: get_f64(&tooltip, "caretX")?,
+            caret_y: get_f64(&tooltip, "caretY")?,
+            x: get_f64(&tooltip, "x")?,
+            y: get_f64(&tooltip, "y")?,
+            x_align: get_string(&tooltip, "xAlign").unwrap_or_default(),
+            y_align: get_string(&tooltip, "yAlign").unwrap_or_default(),
+            title: get_strings(&tooltip, "title"),
+            before_body: get_strings(&tooltip, "beforeBody"),
+            body: Array::from(&get_field(&tooltip, "body")?)
+                .iter()
+                .map(|item| TooltipBodyItem {
+                    before: get_strings(&item, "before"),
+                    lines: get_strings(&item, "lines"),
+                    after: get_strings(&item, "after"),
+                })
+                .collect(),
+            after_body: get_strings(&tooltip, "afterBody"),
+            footer: get_strings(&tooltip, "footer"),
+            data_points: if data_points.is_array() {
+                ChartJsPoint::parse_array(data_points)?
+            } else {
+                Vec::new()
+            },
+            label_colors: Array::from(&get_field(&tooltip, "labelColors")?)
+                .iter()
+                .map(|color| TooltipLabelColor {
+                    border_color: get_string(&color, "borderColor").unwrap_or_default(),
+                    background_color: get_string(&color, "backgroundColor").unwrap_or_default(),
+                })
+                .collect(),
+        })
+    }
+}
+
+/// An active element passed to a positioner registered with [`crate::register_tooltip_positioner`]
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct PositionerElement {
+    pub dataset_index: usize,
+    pub index: usize,
+    /// The element's position, relative to the canvas
+    pub x: f64,
+    /// The element's position, relative to the canvas
+    pub y: f64,
+}
+
+impl PositionerElement {
+    /// Parses the `elements` argument of a Chart.js tooltip positioner
+    pub fn parse_array(elements: &JsValue) -> Result<Vec<Self>, CoordinateError> {
+        Array::from(elements)
+            .iter()
+            .map(|active| {
+                let element = get_field(&active, "element")?;
+                Ok(PositionerElement {
+                    dataset_index: get_f64(&active, "datasetIndex")? as usize,
+                    index: get_f64(&active, "index")? as usize,
+                    x: get_f64(&element, "x")?,
+                    y: get_f64(&element, "y")?,
+                })
+            })
+            .collect()
+    }
+}
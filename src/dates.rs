@@ -0,0 +1,198 @@
+use crate::NumberOrDateString;
+
+/// Converts `chrono` and `time` values into a [`NumberOrDateString`] using one canonical format,
+/// an RFC 3339 timestamp in UTC with millisecond precision, eg. `2024-01-31T13:45:00.000Z`.
+///
+/// Naive dates and datetimes carry no offset, so they are interpreted as UTC.
+///
+/// `NumberOrDateString` already implements `From<T: Display>`, which would format these types
+/// with their own (non-ISO) `Display` impls, so use this instead of `.into()`:
+///
+/// ```rust no_run
+/// let data = readings
+///     .iter()
+///     .map(|r| (r.timestamp.to_chart_date(), r.value))
+///     .into_data_iter()
+///     .unsorted_to_dataset_data();
+/// ```
+pub trait ChartDateExt {
+    fn to_chart_date(&self) -> NumberOrDateString;
+}
+
+#[cfg(feature = "chrono")]
+const CANONICAL_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+
+#[cfg(feature = "chrono")]
+impl ChartDateExt for chrono::NaiveDate {
+    fn to_chart_date(&self) -> NumberOrDateString {
+        self.and_time(chrono::NaiveTime::MIN).to_chart_date()
+    }
+}
+#[cfg(feature = "chrono")]
+impl ChartDateExt for chrono::NaiveDateTime {
+    fn to_chart_date(&self) -> NumberOrDateString {
+        self.format(CANONICAL_FORMAT).into()
+    }
+}
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ChartDateExt for chrono::DateTime<Tz> {
+    fn to_chart_date(&self) -> NumberOrDateString {
+        self.naive_utc().to_chart_date()
+    }
+}
+
+#[cfg(feature = "time")]
+impl ChartDateExt for time::Date {
+    fn to_chart_date(&self) -> NumberOrDateString {
+        self.midnight().to_chart_date()
+    }
+}
+#[cfg(feature = "time")]
+impl ChartDateExt for time::PrimitiveDateTime {
+    fn to_chart_date(&self) -> NumberOrDateString {
+        self.assume_utc().to_chart_date()
+    }
+}
+#[cfg(feature = "time")]
+impl ChartDateExt for time::OffsetDateTime {
+    fn to_chart_date(&self) -> NumberOrDateString {
+        let utc = self.to_offset(time::UtcOffset::UTC);
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            utc.year(),
+            utc.month() as u8,
+            utc.day(),
+            utc.hour(),
+            utc.minute(),
+            utc.second(),
+            utc.millisecond()
+        )
+        .into()
+    }
+}
+
+/// Milliseconds since the Unix epoch for an ISO 8601 date or datetime,
+/// used by `Ord for NumberOrDateString` to compare dates chronologically
+pub(crate) fn timestamp_millis(s: &str) -> Option<i128> {
+    #[cfg(feature = "chrono")]
+    {
+        use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+        if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+            return Some(dt.timestamp_millis().into());
+        }
+        if let Some(dt) = [
+            "%Y-%m-%dT%H:%M:%S%.f",
+            "%Y-%m-%d %H:%M:%S%.f",
+            "%Y-%m-%dT%H:%M",
+        ]
+        .into_iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+        {
+            return Some(dt.and_utc().timestamp_millis().into());
+        }
+        if let Ok(d) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+            return Some(
+                d.and_time(chrono::NaiveTime::MIN)
+                    .and_utc()
+                    .timestamp_millis()
+                    .into(),
+            );
+        }
+    }
+
+    #[cfg(feature = "time")]
+    {
+        use time::{
+            format_description::well_known::Iso8601, Date, OffsetDateTime, PrimitiveDateTime,
+        };
+
+        if let Ok(dt) = OffsetDateTime::parse(s, &Iso8601::DEFAULT) {
+            return Some(dt.unix_timestamp_nanos() / 1_000_000);
+        }
+        if let Ok(dt) = PrimitiveDateTime::parse(s, &Iso8601::DEFAULT) {
+            return Some(dt.assume_utc().unix_timestamp_nanos() / 1_000_000);
+        }
+        if let Ok(d) = Date::parse(s, &Iso8601::DATE) {
+            return Some(d.midnight().assume_utc().unix_timestamp_nanos() / 1_000_000);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_to_chart_date() {
+        use chrono::{FixedOffset, NaiveDate};
+
+        let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert_eq!(date.to_chart_date().to_string(), "2024-01-31T00:00:00.000Z");
+
+        let datetime = date.and_hms_milli_opt(13, 45, 0, 7).unwrap();
+        assert_eq!(
+            datetime.to_chart_date().to_string(),
+            "2024-01-31T13:45:00.007Z"
+        );
+
+        // converted to UTC, crossing midnight
+        let offset = FixedOffset::east_opt(-5 * 3600).unwrap();
+        let local = date
+            .and_hms_milli_opt(22, 30, 15, 250)
+            .unwrap()
+            .and_local_timezone(offset)
+            .unwrap();
+        assert_eq!(
+            local.to_chart_date().to_string(),
+            "2024-02-01T03:30:15.250Z"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_into_sorts_as_text() {
+        use chrono::NaiveDate;
+
+        let earlier = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc();
+        let later = earlier + chrono::Duration::days(1);
+
+        // `Display` for `DateTime<Utc>` appends ` UTC`, which isn't a date Chart.js or `Ord` can read
+        let into: NumberOrDateString = earlier.into();
+        assert_eq!(into.to_string(), "2024-01-01 00:00:00 UTC");
+        assert!(into > later.to_chart_date());
+        assert!(earlier.to_chart_date() < later.to_chart_date());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_to_chart_date() {
+        use time::{Date, Month, UtcOffset};
+
+        let date = Date::from_calendar_date(2024, Month::January, 31).unwrap();
+        assert_eq!(date.to_chart_date().to_string(), "2024-01-31T00:00:00.000Z");
+
+        let datetime = date.with_hms_milli(13, 45, 0, 7).unwrap();
+        assert_eq!(
+            datetime.to_chart_date().to_string(),
+            "2024-01-31T13:45:00.007Z"
+        );
+
+        // converted to UTC, crossing midnight
+        let local = date
+            .with_hms_milli(1, 30, 15, 250)
+            .unwrap()
+            .assume_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(
+            local.to_chart_date().to_string(),
+            "2024-01-30T23:30:15.250Z"
+        );
+    }
+}
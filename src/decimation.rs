@@ -0,0 +1,270 @@
+use serde_json::Value;
+
+use crate::objects::*;
+
+impl XYDataset {
+    /// Downsamples the dataset's `{x, y}` points before it is serialized, to `samples` points with
+    /// [`DecimationAlgorithm::Lttb`], or `samples` buckets of up to 4 points with [`DecimationAlgorithm::MinMax`].
+    ///
+    /// `x` values are read as numbers, then (with the `chrono` or `time` features) as dates,
+    /// and fall back to the point's index, eg. for category labels.
+    /// The data is left untouched if any `y` value isn't a number, as gaps can't be decimated.
+    ///
+    /// ```rust no_run
+    /// XYDataset::new()
+    ///     .data(readings.into_data_iter().unsorted_to_dataset_data())
+    ///     .decimate(DecimationAlgorithm::Lttb, 1_000)
+    /// ```
+    pub fn decimate(mut self, algorithm: DecimationAlgorithm, samples: usize) -> Self {
+        let (x_key, y_key) = Parsing::axis_keys(&self.parsing);
+        let Some(points) = self.data.0.as_array() else {
+            return self;
+        };
+        let Some(xy) = points
+            .iter()
+            .enumerate()
+            .map(|(idx, point)| point_xy(idx, point, x_key, y_key))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return self;
+        };
+
+        let keep = match algorithm {
+            DecimationAlgorithm::Lttb => lttb_indices(&xy, samples),
+            DecimationAlgorithm::MinMax => min_max_indices(&xy, samples),
+        };
+        let data = keep.into_iter().map(|idx| points[idx].clone()).collect();
+
+        self.data = DatasetData(Value::Array(data));
+        self
+    }
+}
+
+/// Largest Triangle Three Buckets downsampling, keeps `threshold` points of the `points`,
+/// which must be sorted by x. `xy` reads the x and y values of each point.
+///
+/// ```rust no_run
+/// let kept = lttb(&readings, 1_000, |r| (r.timestamp.and_utc().timestamp_millis() as f64, r.value));
+/// ```
+pub fn lttb<P: Clone>(points: &[P], threshold: usize, xy: impl Fn(&P) -> (f64, f64)) -> Vec<P> {
+    let xy = points.iter().map(xy).collect::<Vec<_>>();
+    lttb_indices(&xy, threshold)
+        .into_iter()
+        .map(|idx| points[idx].clone())
+        .collect()
+}
+
+fn lttb_indices(xy: &[(f64, f64)], threshold: usize) -> Vec<usize> {
+    if threshold >= xy.len() || threshold < 3 {
+        return (0..xy.len()).collect();
+    }
+
+    let every = (xy.len() - 2) as f64 / (threshold - 2) as f64;
+
+    let mut sampled = Vec::with_capacity(threshold);
+    sampled.push(0);
+
+    let mut a = 0;
+    for i in 0..threshold - 2 {
+        // the average of the next bucket is the third point of the triangle
+        let avg_start = ((i + 1) as f64 * every) as usize + 1;
+        let avg_end = (((i + 2) as f64 * every) as usize + 1).min(xy.len());
+        let avg_len = (avg_end - avg_start) as f64;
+        let (avg_x, avg_y) = xy[avg_start..avg_end]
+            .iter()
+            .fold((0.0, 0.0), |(x, y), p| (x + p.0, y + p.1));
+        let (avg_x, avg_y) = (avg_x / avg_len, avg_y / avg_len);
+
+        let start = (i as f64 * every) as usize + 1;
+        let end = ((i + 1) as f64 * every) as usize + 1;
+        let (ax, ay) = xy[a];
+        let next_a = (start..end)
+            .max_by(|&l, &r| {
+                let area = |idx: usize| {
+                    let (x, y) = xy[idx];
+                    ((ax - avg_x) * (y - ay) - (ax - x) * (avg_y - ay)).abs()
+                };
+                area(l).total_cmp(&area(r))
+            })
+            .unwrap_or(start);
+
+        sampled.push(next_a);
+        a = next_a;
+    }
+
+    sampled.push(xy.len() - 1);
+    sampled
+}
+
+/// Min/max downsampling, splits the x range of the `points` into `samples` buckets and keeps
+/// the first, last, minimum and maximum point of each, so peaks are never lost.
+/// `points` must be sorted by x, `xy` reads the x and y values of each point.
+pub fn min_max<P: Clone>(points: &[P], samples: usize, xy: impl Fn(&P) -> (f64, f64)) -> Vec<P> {
+    let xy = points.iter().map(xy).collect::<Vec<_>>();
+    min_max_indices(&xy, samples)
+        .into_iter()
+        .map(|idx| points[idx].clone())
+        .collect()
+}
+
+fn min_max_indices(xy: &[(f64, f64)], samples: usize) -> Vec<usize> {
+    if samples == 0 || xy.len() <= samples.saturating_mul(4) {
+        return (0..xy.len()).collect();
+    }
+
+    let first_x = xy[0].0;
+    let range = xy[xy.len() - 1].0 - first_x;
+    let bucket = |x: f64| {
+        if range > 0.0 {
+            (((x - first_x) / range * samples as f64) as usize).min(samples - 1)
+        } else {
+            0
+        }
+    };
+
+    let mut kept = Vec::new();
+    let mut start = 0;
+    while start < xy.len() {
+        let current = bucket(xy[start].0);
+        let end = (start..xy.len())
+            .find(|&idx| bucket(xy[idx].0) != current)
+            .unwrap_or(xy.len());
+
+        let min = (start..end)
+            .min_by(|&l, &r| xy[l].1.total_cmp(&xy[r].1))
+            .unwrap_or(start);
+        let max = (start..end)
+            .max_by(|&l, &r| xy[l].1.total_cmp(&xy[r].1))
+            .unwrap_or(start);
+
+        let mut idxs = [start, min, max, end - 1];
+        idxs.sort_unstable();
+        kept.extend(
+            idxs.into_iter()
+                .enumerate()
+                .filter(|(i, idx)| *i == 0 || idxs[i - 1] != *idx)
+                .map(|(_, idx)| idx),
+        );
+
+        start = end;
+    }
+    kept
+}
+
+fn point_xy(idx: usize, point: &Value, x_key: &str, y_key: &str) -> Option<(f64, f64)> {
+    let point = point.as_object()?;
+    let y = number(point.get(y_key)?)?;
+    let x = point
+        .get(x_key)
+        .and_then(|x| number(x).or_else(|| date_millis(x)))
+        .unwrap_or(idx as f64);
+
+    Some((x, y))
+}
+
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn date_millis(value: &Value) -> Option<f64> {
+    crate::dates::timestamp_millis(value.as_str()?).map(|ms| ms as f64)
+}
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+fn date_millis(_value: &Value) -> Option<f64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a noisy signal, so every bucket has a distinct min and max
+    fn signal(len: usize) -> Vec<(f64, f64)> {
+        (0..len)
+            .map(|i| (i as f64, ((i * 7919) % 101) as f64 - 50.0))
+            .collect()
+    }
+
+    fn assert_sorted_unique(idxs: &[usize]) {
+        assert!(idxs.windows(2).all(|w| w[0] < w[1]), "{idxs:?}");
+    }
+
+    #[test]
+    fn lttb_keeps_threshold_points() {
+        let xy = signal(1_000);
+        for threshold in [3, 10, 100, 999] {
+            let kept = lttb_indices(&xy, threshold);
+            assert_eq!(kept.len(), threshold);
+            assert_eq!(kept.first(), Some(&0));
+            assert_eq!(kept.last(), Some(&999));
+            assert_sorted_unique(&kept);
+        }
+    }
+
+    #[test]
+    fn lttb_leaves_small_thresholds_and_short_data() {
+        let xy = signal(50);
+        let all = (0..50).collect::<Vec<_>>();
+        for threshold in [0, 1, 2, 50, 51, 1_000] {
+            assert_eq!(lttb_indices(&xy, threshold), all);
+        }
+    }
+
+    #[test]
+    fn lttb_maps_indices_to_points() {
+        let points = signal(100);
+        let kept = lttb(&points, 10, |p| *p);
+        assert_eq!(kept.len(), 10);
+        assert_eq!(kept[0], points[0]);
+        assert_eq!(kept[9], points[99]);
+    }
+
+    #[test]
+    fn min_max_keeps_at_most_four_points_per_bucket() {
+        let xy = signal(1_000);
+        for samples in [1, 10, 100, 249] {
+            let kept = min_max_indices(&xy, samples);
+            assert!(
+                kept.len() <= samples * 4,
+                "{} > {}",
+                kept.len(),
+                samples * 4
+            );
+            assert!(kept.len() >= samples * 2);
+            assert_eq!(kept.first(), Some(&0));
+            assert_eq!(kept.last(), Some(&999));
+            assert_sorted_unique(&kept);
+        }
+    }
+
+    #[test]
+    fn min_max_keeps_peaks() {
+        let mut xy = signal(1_000);
+        xy[500].1 = 1_000.0;
+        xy[501].1 = -1_000.0;
+        let kept = min_max_indices(&xy, 10);
+        assert!(kept.contains(&500));
+        assert!(kept.contains(&501));
+    }
+
+    #[test]
+    fn min_max_leaves_short_data_and_zero_samples() {
+        let xy = signal(40);
+        let all = (0..40).collect::<Vec<_>>();
+        for samples in [0, 10, 11, 1_000] {
+            assert_eq!(min_max_indices(&xy, samples), all);
+        }
+    }
+
+    #[test]
+    fn min_max_handles_a_single_x() {
+        let xy = (0..100).map(|i| (1.0, i as f64)).collect::<Vec<_>>();
+        assert_eq!(min_max_indices(&xy, 5), [0, 99]);
+        assert_eq!(min_max(&xy, 5, |p| *p), [xy[0], xy[99]]);
+    }
+}
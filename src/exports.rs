@@ -6,5 +6,20 @@ extern "C" {
 
     pub fn render_chart(v: JsValue, id: &str, mutate: bool, plugins: String, defaults: String);
 
-    pub fn update_chart(updated: JsValue, id: &str, animate: bool) -> bool;
+    pub fn update_chart(
+        updated: JsValue,
+        id: &str,
+        mode: Option<String>,
+        transition: JsValue,
+    ) -> bool;
+
+    pub fn reset_zoom(id: &str) -> bool;
+
+    pub fn zoom_scale(
+        id: &str,
+        scale_id: &str,
+        min: JsValue,
+        max: JsValue,
+        mode: Option<String>,
+    ) -> bool;
 }
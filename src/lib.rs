@@ -3,12 +3,16 @@
 
 pub mod bar;
 pub mod coordinate;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub mod dates;
+pub mod decimation;
 pub mod doughnut;
 pub mod exports;
 pub mod functions;
 pub mod objects;
 pub mod pie;
 pub mod scatter;
+pub mod stacking;
 pub mod traits;
 
 #[cfg(feature = "workers")]
@@ -18,6 +22,9 @@ pub use objects::*;
 pub use traits::*;
 pub use utils::*;
 
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use dates::*;
+
 #[cfg(feature = "workers")]
 pub use worker_chart::*;
 
@@ -27,6 +34,7 @@ mod utils;
 use exports::get_chart;
 use gloo_utils::format::JsValueSerdeExt;
 use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashSet;
 
 #[cfg(feature = "workers")]
 use wasm_bindgen::{self, prelude::*};
@@ -67,6 +75,44 @@ pub trait ChartExt: DeserializeOwned + Serialize + Default {
         }
     }
 
+    /// Chart.js silently drops zero and negative values from `logarithmic` scales.
+    /// This checks every dataset bound to a logarithmic scale (through `xAxisID`/`yAxisID`)
+    /// for such values before rendering, and reports or clamps them depending on `policy`.
+    fn check_log_axes<D: DatasetTrait>(&mut self, policy: LogAxisPolicy) -> Vec<LogAxisViolation>
+    where
+        Self: ChartExt<DS = Dataset<D>>,
+    {
+        let log_axes = self
+            .get_options()
+            .scales
+            .iter()
+            .flatten()
+            .filter(|(_, scale)| scale.r#type == Some(ScaleType::Logarithmic))
+            .map(|(id, _)| id.clone())
+            .collect::<HashSet<_>>();
+
+        let index_axis = self.get_options().indexAxis.clone();
+        let violations =
+            self.get_data()
+                .get_datasets()
+                .check_log_axes(&log_axes, &index_axis, &policy);
+
+        if policy == LogAxisPolicy::Report {
+            violations.iter().for_each(|v| {
+                gloo_console::warn!(format!(
+                    "Dataset {} (`{}`) has the value {} at index {}, which cannot be plotted on the logarithmic scale `{}`.",
+                    v.dataset,
+                    v.label,
+                    v.value.to_string(),
+                    v.index,
+                    v.axis
+                ))
+            });
+        }
+
+        violations
+    }
+
     fn get_chart_from_id(id: &str) -> Option<Self> {
         let chart = get_chart(id);
 
@@ -122,8 +168,59 @@ mod worker_chart {
                 .await
         }
 
-        pub async fn update_async(self, animate: bool) -> Result<bool, Box<dyn std::error::Error>> {
-            self.worker.update(self.obj, &self.id, animate).await
+        pub async fn update_async(
+            self,
+            mode: impl Into<UpdateMode>,
+        ) -> Result<bool, Box<dyn std::error::Error>> {
+            self.worker
+                .update(self.obj, &self.id, &mode.into(), JsValue::UNDEFINED)
+                .await
+        }
+
+        /// Updates the chart, animated with `transition` for this update only, see [`Chart::update_with_transition`]
+        pub async fn update_with_transition_async(
+            self,
+            mode: impl Into<UpdateMode>,
+            transition: Transition,
+        ) -> Result<bool, Box<dyn std::error::Error>> {
+            let transition = crate::utils::transition_to_js(&transition);
+            self.worker
+                .update(self.obj, &self.id, &mode.into(), transition)
+                .await
+        }
+
+        /// Resets the zoom and pan of the chart in the worker, requires chartjs-plugin-zoom in the worker's imports.
+        /// Returns `false` if there is no such chart.
+        pub async fn reset_zoom_async(&self) -> Result<bool, Box<dyn std::error::Error>> {
+            self.worker
+                .zoom(
+                    &self.id,
+                    None,
+                    (JsValue::UNDEFINED, JsValue::UNDEFINED),
+                    &UpdateMode::Default,
+                )
+                .await
+        }
+
+        /// Zooms a scale of the chart in the worker to `min..max`, see [`Chart::zoom_scale`]
+        pub async fn zoom_scale_async(
+            &self,
+            scale_id: &str,
+            min: impl Into<NumberOrDateString>,
+            max: impl Into<NumberOrDateString>,
+            mode: impl Into<UpdateMode>,
+        ) -> Result<bool, Box<dyn std::error::Error>> {
+            let to_js = |value: NumberOrDateString| {
+                <JsValue as JsValueSerdeExt>::from_serde(&value).unwrap_or_default()
+            };
+            self.worker
+                .zoom(
+                    &self.id,
+                    Some(scale_id.to_string()),
+                    (to_js(min.into()), to_js(max.into())),
+                    &mode.into(),
+                )
+                .await
         }
 
         #[must_use = "\nAppend .render_async()\n"]
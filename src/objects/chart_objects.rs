@@ -1,8 +1,18 @@
 use {
-    crate::{objects::helper_objects::*, traits::*},
+    crate::{
+        coordinate::{
+            AnimationProgress, AnnotationContext, AnnotationEvent, ChartEvent, ChartResize,
+            CoordinateError, DataLabelEvent, LegendEvent, ScaleRanges, TooltipModel,
+        },
+        objects::helper_objects::*,
+        traits::*,
+    },
     serde::{de, Deserialize, Serialize},
     serde_json::Value,
-    std::{collections::HashMap, fmt::Debug},
+    std::{
+        collections::{BTreeMap, HashMap, HashSet},
+        fmt::Debug,
+    },
 };
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -15,6 +25,9 @@ pub struct SinglePointDataset {
     pub(crate) barThickness: NumberString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) base: NumberString,
+    /// `center` or `inner`, for every slice or per slice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) borderAlign: Option<OneOrMany<String>>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) borderColor: String,
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -28,7 +41,11 @@ pub struct SinglePointDataset {
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) categoryPercentage: NumberString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) circumference: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) clip: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) cutout: NumberString,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub(crate) data: Vec<NumberString>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -43,6 +60,9 @@ pub struct SinglePointDataset {
     pub(crate) hoverBorderRadius: NumberString,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) hoverBorderWidth: Option<NumberStringOrT<Border>>,
+    /// A number for every slice, or a number per slice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hoverOffset: Option<OneOrMany<NumberString>>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) indexAxis: String,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
@@ -53,8 +73,13 @@ pub struct SinglePointDataset {
     pub(crate) maxBarThickness: NumberString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) minBarLength: NumberString,
+    /// A number for every slice, or a number per slice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) offset: Option<OneOrMany<NumberString>>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) order: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parsing: Option<Parsing>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) pointBackgroundColor: String,
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -71,15 +96,25 @@ pub struct SinglePointDataset {
     pub(crate) pointRadius: NumberString,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) pointStyle: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) radius: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) rotation: NumberString,
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) r#type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) skipNull: Option<bool>,
+    /// A number for every slice, or a number per slice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) spacing: Option<OneOrMany<NumberString>>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) stack: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) stepped: Option<bool>,
+    /// The relative thickness of the dataset's ring, compared to the other datasets
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) weight: NumberString,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) xAxisID: String,
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -88,7 +123,11 @@ pub struct SinglePointDataset {
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct XYDataset {
-    #[serde(skip_serializing_if = "FnWithArgsOrT::is_empty", default)]
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
     pub(crate) backgroundColor: FnWithArgsOrT<1, String>,
     #[serde(
         skip_serializing_if = "Vec::is_empty",
@@ -103,9 +142,13 @@ pub struct XYDataset {
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) base: NumberString,
     #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderCapStyle: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) borderColor: String,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub(crate) borderDash: Vec<NumberString>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) borderDashOffset: NumberString,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) borderJoinStyle: String,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
@@ -120,16 +163,22 @@ pub struct XYDataset {
     pub(crate) categoryPercentage: NumberString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) clip: NumberString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) cubicInterpolationMode: String,
     #[serde(skip_serializing_if = "DatasetData::is_empty", default)]
     pub(crate) data: DatasetData,
-    /// Use Default::default() if this isn't required
-    pub(crate) datalabels: DataLabels,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) datalabels: Option<DataLabels>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) description: String,
-    #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) fill: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) drawActiveElementsOnTop: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fill: Option<Fill>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) grouped: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) hidden: Option<bool>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) hitRadius: NumberString,
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -150,8 +199,12 @@ pub struct XYDataset {
     pub(crate) maxBarThickness: NumberString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) minBarLength: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) normalized: Option<bool>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) order: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parsing: Option<Parsing>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) pointBackgroundColor: String,
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -162,24 +215,30 @@ pub struct XYDataset {
     pub(crate) pointHitRadius: NumberString,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) pointHoverBackgroundColor: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) pointHoverBorderColor: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) pointHoverBorderWidth: Option<NumberStringOrT<Border>>,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) pointHoverRadius: NumberOrDateString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) pointRadius: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) pointRotation: NumberString,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) pointStyle: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) segment: Option<Segment>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) showLine: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) skipNull: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) spanGaps: Option<bool>,
+    pub(crate) spanGaps: Option<SpanGaps>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) stack: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) stepped: Option<BoolString>,
+    pub(crate) stepped: Option<Stepped>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) tension: NumberString,
     #[serde(rename = "type")]
@@ -194,7 +253,11 @@ pub struct XYDataset {
 }
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct FloatingDataset {
-    #[serde(skip_serializing_if = "FnWithArgsOrT::is_empty", default)]
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
     pub(crate) backgroundColor: FnWithArgsOrT<2, String>,
     #[serde(
         skip_serializing_if = "Vec::is_empty",
@@ -228,12 +291,12 @@ pub struct FloatingDataset {
     pub(crate) clip: NumberString,
     #[serde(skip_serializing_if = "DatasetData::is_empty", default)]
     pub(crate) data: DatasetData,
-    /// Use Default::default() if this isn't required
-    pub(crate) datalabels: DataLabels,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) datalabels: Option<DataLabels>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) description: String,
-    #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) fill: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fill: Option<Fill>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) grouped: Option<bool>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
@@ -258,6 +321,8 @@ pub struct FloatingDataset {
     pub(crate) minBarLength: NumberString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) order: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parsing: Option<Parsing>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) pointBackgroundColor: String,
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -281,11 +346,11 @@ pub struct FloatingDataset {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) skipNull: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) spanGaps: Option<bool>,
+    pub(crate) spanGaps: Option<SpanGaps>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) stack: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) stepped: Option<BoolString>,
+    pub(crate) stepped: Option<Stepped>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) tension: NumberString,
     #[serde(rename = "type")]
@@ -310,12 +375,112 @@ pub struct XYPoint {
     pub(crate) description: serde_json::Value,
 }
 
+/// The area fill of a line or radar dataset, see <https://www.chartjs.org/docs/latest/charts/area.html>
+///
+/// Strings are parsed the way Chart.js does, so `"origin"`, `"-1"` and `"+1"` all still work.
+/// A min/max envelope fills between two datasets:
+///
+/// ```rust no_run
+/// let max = XYDataset::new().label("Max").data(max_data);
+/// let min = XYDataset::new().label("Min").data(min_data).fill(
+///     FillTarget::new()
+///         .target(Fill::Relative(-1))
+///         .above("rgba(54, 162, 235, 0.2)")
+///         .below("rgba(255, 99, 132, 0.2)"),
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Fill {
+    Disabled,
+    #[default]
+    Origin,
+    Start,
+    End,
+    Stack,
+    Shape,
+    /// Fill to the dataset at this index
+    Dataset(usize),
+    /// Fill to the dataset this many positions away, eg. `-1` for the previous dataset
+    Relative(isize),
+    /// Fill to a horizontal line at this value
+    Value(NumberString),
+    /// Fill to a target, with different colours above and below it
+    Target(FillTarget),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FillTarget {
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) above: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) below: String,
+    pub(crate) target: Box<Fill>,
+}
+
+/// Whether a line is drawn over missing (`null`) points, or the largest gap
+/// (in milliseconds for time scales) that is still spanned
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(untagged)]
+pub enum SpanGaps {
+    Bool(bool),
+    MaxGap(NumberString),
+}
+
+/// The stepped line interpolation, `true` is the same as `before`
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stepped {
+    #[default]
+    Disabled,
+    Before,
+    After,
+    Middle,
+}
+
+/// How Chart.js reads values from each data record, either `false`
+/// (the data is already in Chart.js' internal format) or the keys to read
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Parsing {
+    Disabled,
+    Keys(ParsingKeys),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ParsingKeys {
+    /// The key of the value in pie and doughnut records.
+    /// [`SinglePointDataset`] data only takes numbers, so this only applies to records added in JS, eg. by `mutate`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) key: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) xAxisKey: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) yAxisKey: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq)]
 pub struct ChartOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) animation: Option<Animation>,
+    /// Per-property animations, keyed by name, eg. `"x"` or `"colors"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) animations: Option<HashMap<String, AnimationProperty>>,
+    /// Width divided by height, ignored unless `maintainAspectRatio` is set
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) aspectRatio: NumberString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderAlign: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) circumference: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) cutout: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) devicePixelRatio: NumberString,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) elements: Option<ChartElements>,
+    /// The browser events the chart listens to, eg. `click` or `mousemove`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) events: Vec<String>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) hoverOffset: NumberString,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) interaction: Option<ChartInteraction>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -324,22 +489,170 @@ pub struct ChartOptions {
     pub(crate) legend: Option<ChartLegend>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) layout: Option<ChartLayout>,
+    /// The locale used to format numbers, eg. `en-GB`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) locale: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) maintainAspectRatio: Option<bool>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) offset: NumberString,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) onClick: FnWithArgs<3>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) onHover: FnWithArgs<3>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) onResize: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parsing: Option<Parsing>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) plugins: Option<ChartPlugins>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) radius: NumberString,
+    /// Milliseconds to debounce resizes by
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) resizeDelay: NumberString,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) responsive: Option<bool>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) rotation: NumberString,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) scales: Option<HashMap<String, ChartScale>>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) spacing: NumberString,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) spanGaps: Option<bool>,
+    pub(crate) spanGaps: Option<SpanGaps>,
+    /// Animation overrides for each update mode, keyed by `active`, `hide`, `reset`, `resize`, `show` or a custom mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) transitions: Option<HashMap<String, Transition>>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) weight: NumberString,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Animation {
+    /// Scriptable, staggered animations use the data index, eg. `context.dataIndex * 100`
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) delay: FnWithArgsOrT<1, NumberString>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) duration: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) easing: Option<Easing>,
+    #[serde(rename = "loop")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) r#loop: Option<bool>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) onComplete: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) onProgress: FnWithArgs<1>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AnimationProperty {
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) delay: FnWithArgsOrT<1, NumberString>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) duration: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) easing: Option<Easing>,
+    /// The start value, a number or a colour
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) from: NumberString,
+    #[serde(rename = "loop")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) r#loop: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) properties: Vec<String>,
+    /// The end value, a number or a colour
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) to: NumberString,
+    /// One of `number`, `color` or `boolean`
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) r#type: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq)]
+pub struct Transition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) animation: Option<Animation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) animations: Option<HashMap<String, AnimationProperty>>,
+}
+
+/// Where the tooltip is drawn, `Custom` names a positioner added with [`crate::register_tooltip_positioner`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TooltipPosition {
+    Average,
+    Nearest,
+    Custom(String),
+}
+
+/// The mode passed to `chart.update()`, each named mode animates with the matching
+/// entry in `options.transitions`, see <https://www.chartjs.org/docs/latest/developers/api.html#updatemode>
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UpdateMode {
+    /// `chart.update()`, animated with `options.animation`
+    #[default]
+    Default,
+    /// Skips all animations
+    None,
+    Active,
+    Show,
+    Hide,
+    Reset,
+    Resize,
+    /// A transition name added with `ChartOptions::transitions`
+    Custom(String),
+}
+
+/// See <https://www.chartjs.org/docs/latest/configuration/animations.html#easing>
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum Easing {
+    Linear,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutQuad,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic,
+    EaseInQuart,
+    EaseOutQuart,
+    EaseInOutQuart,
+    EaseInQuint,
+    EaseOutQuint,
+    EaseInOutQuint,
+    EaseInSine,
+    EaseOutSine,
+    EaseInOutSine,
+    EaseInExpo,
+    EaseOutExpo,
+    EaseInOutExpo,
+    EaseInCirc,
+    EaseOutCirc,
+    EaseInOutCirc,
+    EaseInElastic,
+    EaseOutElastic,
+    EaseInOutElastic,
+    EaseInBack,
+    EaseOutBack,
+    EaseInOutBack,
+    EaseInBounce,
+    EaseOutBounce,
+    EaseInOutBounce,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq)]
@@ -350,12 +663,24 @@ pub struct ChartPlugins {
     pub(crate) autocolors: Option<AutoColors>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) canvasBackgroundColor: Option<CanvasBackgroundColor>,
+    /// Built in, rendered charts add the plugin when this is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) crosshair: Option<CrosshairPlugin>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) decimation: Option<Decimation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) filler: Option<FillerPlugin>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) legend: Option<PluginLegend>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) subtitle: Option<Title>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) title: Option<Title>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) tooltip: Option<TooltipPlugin>,
+    /// Requires chartjs-plugin-zoom
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) zoom: Option<ZoomPlugin>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -364,117 +689,436 @@ pub struct CanvasBackgroundColor {
     pub(crate) color: String,
 }
 
+/// Lines through the hovered position, with badges showing its value on the axes
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct PluginLegend {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) display: Option<bool>,
+pub struct CrosshairPlugin {
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) badgeBackgroundColor: String,
+    /// The colour of the badge text
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) badgeColor: String,
+    /// Defaults to `true`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) labels: Option<LegendLabel>,
+    pub(crate) badges: Option<bool>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) position: String,
+    pub(crate) color: String,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) dash: Vec<NumberString>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) reverse: Option<bool>,
+    pub(crate) font: Option<Font>,
+    /// Defaults to `false`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) horizontal: Option<bool>,
+    /// Moves the vertical line to the nearest data point on the x axis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) snap: Option<bool>,
+    /// Defaults to `true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) vertical: Option<bool>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) width: NumberString,
+    /// The scale the vertical line's badge reads, defaults to `x`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) xScaleID: String,
+    /// The scale the horizontal line's badge reads, defaults to `y`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) yScaleID: String,
 }
 
+/// See <https://www.chartjs.org/chartjs-plugin-zoom/latest/guide/options.html>
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq)]
-pub struct Annotations {
+pub struct ZoomPlugin {
+    /// Keyed by scale id, eg. `"x"`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) annotations: Option<HashMap<String, Annotation>>,
+    pub(crate) limits: Option<HashMap<String, ZoomLimits>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) pan: Option<PanOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) zoom: Option<ZoomOptions>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct AutoColors {
-    #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) mode: String,
+pub struct ZoomLimits {
+    /// A number, or `original` for the scale's range before any zooming
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) max: NumberString,
+    /// A number, or `original` for the scale's range before any zooming
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) min: NumberString,
+    /// The smallest range the scale can be zoomed into
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) minRange: NumberString,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct TooltipPlugin {
+pub struct PanOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enabled: Option<bool>,
+    /// One of `x`, `y` or `xy`
     #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) backgroundColor: String,
+    pub(crate) mode: String,
+    /// One of `ctrl`, `alt`, `shift` or `meta`
     #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) bodyAlign: String,
+    pub(crate) modifierKey: String,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) onPanComplete: FnWithArgs<1>,
+    /// Which axes are panned, when the pointer is over them
     #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) bodyColor: String,
+    pub(crate) scaleMode: String,
+    /// Pixels to move before panning starts
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) threshold: NumberString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ZoomOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) callbacks: Option<TooltipCallbacks>,
+    pub(crate) drag: Option<ZoomDrag>,
+    /// One of `x`, `y` or `xy`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) mode: String,
     #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
     // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
-    pub(crate) filter: FnWithArgs<1>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) displayColors: Option<bool>,
+    pub(crate) onZoomComplete: FnWithArgs<1>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) enabled: Option<bool>,
-    #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) titleAlign: String,
+    pub(crate) pinch: Option<ZoomPinch>,
+    /// Which axes are zoomed, when the pointer is over them
     #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) titleColor: String,
-    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
-    pub(crate) titleMarginBottom: NumberString,
+    pub(crate) scaleMode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) wheel: Option<ZoomWheel>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ChartLayout {
+pub struct ZoomWheel {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) padding: Option<Padding>,
+    pub(crate) enabled: Option<bool>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) modifierKey: String,
+    /// The zoom factor of each wheel step, eg. `0.1`
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) speed: NumberString,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct TooltipCallbacks {
-    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
-    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
-    pub(crate) label: FnWithArgs<1>,
-    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
-    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
-    pub(crate) title: FnWithArgs<1>,
+pub struct ZoomPinch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enabled: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ChartScale {
-    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
-    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
-    pub(crate) afterBuildTicks: FnWithArgs<1>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) alignToPixels: Option<bool>,
+pub struct ZoomDrag {
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) backgroundColor: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderColor: String,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
-    pub(crate) barPercentage: NumberString,
-    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
-    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
-    pub(crate) beforeFit: FnWithArgs<1>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) beginAtZero: Option<bool>,
+    pub(crate) borderWidth: NumberString,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) border: Option<ScaleBorder>,
+    pub(crate) enabled: Option<bool>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) bounds: String,
+    pub(crate) modifierKey: String,
+    /// Pixels to drag before zooming starts
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
-    pub(crate) categoryPercentage: NumberString,
+    pub(crate) threshold: NumberString,
+}
+
+/// Chart.js only decimates line datasets on a linear or time x axis, with `parsing` disabled,
+/// to decimate before serializing see [`XYDataset::decimate`]
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Decimation {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) display: Option<bool>,
-    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
-    pub(crate) grace: NumberOrDateString,
+    pub(crate) algorithm: Option<DecimationAlgorithm>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) grid: Option<Grid>,
+    pub(crate) enabled: Option<bool>,
+    /// The number of samples kept by `lttb`, defaults to the canvas width
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) samples: NumberString,
+    /// Only decimate datasets with more points than this
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) threshold: NumberString,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DecimationAlgorithm {
+    /// Largest Triangle Three Buckets, keeps the overall shape of the series
+    #[serde(rename = "lttb")]
+    Lttb,
+    /// Keeps the first, last, minimum and maximum point of each bucket, so peaks are never lost
+    #[serde(rename = "min-max")]
+    MinMax,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FillerPlugin {
+    /// One of `beforeDraw`, `beforeDatasetDraw` or `beforeDatasetsDraw`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) drawTime: String,
+    /// Fill to the next visible dataset when the target dataset is hidden
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) grouped: Option<bool>,
+    pub(crate) propagate: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PluginLegend {
+    /// One of `start`, `center` or `end`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) align: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) labels: Option<Vec<NumberOrDateString>>,
-    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
-    pub(crate) max: NumberOrDateString,
-    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
-    pub(crate) min: NumberOrDateString,
+    pub(crate) display: Option<bool>,
+    /// Take the full width or height of the canvas, pushing the other boxes aside
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) offset: Option<bool>,
+    pub(crate) fullSize: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) labels: Option<LegendLabel>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) maxHeight: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) maxWidth: NumberString,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) onClick: FnWithArgs<3>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) onHover: FnWithArgs<3>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) onLeave: FnWithArgs<3>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) position: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) reverse: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) stacked: Option<bool>,
-    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
-    pub(crate) suggestedMax: NumberOrDateString,
+    pub(crate) rtl: Option<bool>,
+    /// `ltr` or `rtl`, regardless of the canvas' text direction
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) textDirection: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<LegendTitle>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LegendTitle {
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) color: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) display: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) padding: Option<NumberStringOrT<Padding>>,
+    /// A single line, or each line of the title
+    #[serde(skip_serializing_if = "TitleText::is_empty", default)]
+    pub(crate) text: TitleText,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq)]
+pub struct Annotations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) annotations: Option<HashMap<String, Annotation>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AutoColors {
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) mode: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TooltipPlugin {
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) backgroundColor: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) bodyAlign: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) bodyColor: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) bodyFont: Option<Font>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) bodySpacing: NumberString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderColor: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) borderWidth: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) boxHeight: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) boxPadding: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) boxWidth: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) callbacks: Option<TooltipCallbacks>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) caretPadding: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) caretSize: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) cornerRadius: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) displayColors: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enabled: Option<bool>,
+    /// Renders the tooltip yourself, see [`TooltipPlugin::external_html`]
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) external: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) filter: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) footerAlign: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) footerColor: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) footerFont: Option<Font>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) footerMarginTop: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) footerSpacing: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) intersect: Option<bool>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) itemSort: FnWithArgs<3>,
+    /// One of `average`, `nearest` or the interaction modes, eg. `index`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) mode: String,
+    /// The background behind each colour box when several items are shown
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) multiKeyBackground: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) padding: Option<NumberStringOrT<Padding>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) position: Option<TooltipPosition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) rtl: Option<bool>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) textDirection: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) titleAlign: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) titleColor: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) titleFont: Option<Font>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) titleMarginBottom: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) titleSpacing: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) usePointStyle: Option<bool>,
+    /// One of `left`, `center` or `right`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) xAlign: String,
+    /// One of `top`, `center` or `bottom`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) yAlign: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChartLayout {
+    /// Pads the chart area so points near the edges aren't clipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) autoPadding: Option<bool>,
+    /// A single number pads every side
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) padding: Option<NumberStringOrT<Padding>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TooltipCallbacks {
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) afterBody: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) afterFooter: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) afterLabel: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) afterTitle: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) beforeBody: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) beforeFooter: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) beforeLabel: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) beforeTitle: FnWithArgs<1>,
+    /// eg. a total of every item in the tooltip
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) footer: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) label: FnWithArgs<1>,
+    /// Returns `{ borderColor, backgroundColor }` for the item's colour box
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) labelColor: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) labelPointStyle: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) labelTextColor: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) title: FnWithArgs<1>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChartScale {
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) afterBuildTicks: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) alignToPixels: Option<bool>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) backgroundColor: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) barPercentage: NumberString,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) beforeFit: FnWithArgs<1>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) beginAtZero: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) border: Option<ScaleBorder>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) bounds: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) categoryPercentage: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) display: Option<bool>,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) grace: NumberOrDateString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) grid: Option<Grid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) grouped: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) labels: Option<Vec<NumberOrDateString>>,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) max: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) min: NumberOrDateString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) offset: Option<bool>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) position: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) reverse: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) stacked: Option<bool>,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) suggestedMax: NumberOrDateString,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) suggestedMin: NumberOrDateString,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -483,13 +1127,25 @@ pub struct ChartScale {
     pub(crate) time: Option<ScaleTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) title: Option<Title>,
-    #[serde(skip_serializing_if = "String::is_empty", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "type")]
-    pub(crate) r#type: String,
+    pub(crate) r#type: Option<ScaleType>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) weight: NumberString,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScaleType {
+    Category,
+    Linear,
+    Logarithmic,
+    RadialLinear,
+    Time,
+    TimeSeries,
+    /// A scale type registered by a plugin, or one not yet covered here
+    Custom(String),
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ScaleBorder {
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -508,17 +1164,47 @@ pub struct ScaleBorder {
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Grid {
-    #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) color: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) circular: Option<bool>,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) color: FnWithArgsOrT<1, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) display: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) drawOnChartArea: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) drawTicks: Option<bool>,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) lineWidth: FnWithArgsOrT<1, NumberString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) offset: Option<bool>,
-    #[serde(skip_serializing_if = "String::is_empty", default, skip_deserializing)]
-    // the skip_deserializing needed because chartjs sets a default with a different type, FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
-    pub(crate) tickColor: String,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) tickBorderDash: Vec<NumberString>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) tickBorderDashOffset: NumberString,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    // chartjs sets a default function (falling back to `color`) here
+    pub(crate) tickColor: FnWithArgsOrT<1, String>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) tickLength: NumberString,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) tickWidth: FnWithArgsOrT<1, NumberString>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) z: NumberString,
 }
@@ -543,6 +1229,9 @@ pub struct Callout {
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LabelAnnotation {
+    /// Whether the scale ranges are extended to fit the annotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) adjustScaleRange: Option<bool>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) backgroundColor: String,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
@@ -557,23 +1246,55 @@ pub struct LabelAnnotation {
     pub(crate) callout: Option<Callout>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) color: String,
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    pub(crate) content: Vec<String>,
+    /// Lines of text, an image or a scriptable function, see [`LabelAnnotation::content_closure`]
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) content: FnWithArgsOrT<1, LabelContent>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) drawTime: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) display: Option<bool>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) enter: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) leave: FnWithArgs<2>,
+    /// The opacity of image content, between `0` and `1`
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) opacity: NumberString,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) click: FnWithArgs<2>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) display: Option<FnWithArgsOrT<1, BoolString>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) font: Option<Font>,
+    /// Overrides the height of the content, eg. to scale an image, a number or a percentage
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) height: NumberString,
     #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
     // the skip_deserializing needed because chartjs sets a default with a different type
     pub(crate) padding: Option<Padding>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) position: String,
-    #[serde(default, rename = "type")]
-    pub(crate) r#type: LabelAnnotationType,
+    /// In degrees
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) rotation: NumberString,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) textAlign: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) textStrokeColor: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) textStrokeWidth: NumberString,
+    /// Overrides the width of the content, eg. to scale an image, a number or a percentage
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) width: NumberString,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) xValue: NumberOrDateString,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
@@ -586,46 +1307,88 @@ pub struct LabelAnnotation {
     pub(crate) xMax: NumberOrDateString,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) xMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) xScaleID: String,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) yMax: NumberOrDateString,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) yMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) yScaleID: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) z: NumberString,
+}
+
+/// Image content for a [`LabelAnnotation`], loaded into an `Image` when the chart is rendered.
+/// Only charts rendered on the main thread can show images, charts rendered in a `WorkerChart` have no `Image`.
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LabelImage {
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) height: NumberString,
+    /// A url, or a `data:` url, see [`LabelImage::from_bytes`]
+    pub(crate) src: String,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
-    pub(crate) yScaleID: NumberString,
+    pub(crate) width: NumberString,
 }
-#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct LabelAnnotationType;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LineAnnotation {
+    /// Whether the scale ranges are extended to fit the annotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) adjustScaleRange: Option<bool>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) borderColor: String,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub(crate) borderDash: Vec<NumberString>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) borderWidth: Option<NumberStringOrT<Border>>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) click: FnWithArgs<2>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) display: Option<FnWithArgsOrT<1, BoolString>>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) drawTime: String,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) enter: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) leave: FnWithArgs<2>,
+    /// Where the line ends on `scaleID`, defaults to `value`
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) endValue: NumberOrDateString,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) label: Option<LabelAnnotation>,
-    #[serde(default, rename = "type")]
-    pub(crate) r#type: LineAnnotationType,
+    /// The scale the line crosses at `value`, instead of using `xMin`/`xMax`/`yMin`/`yMax`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) scaleID: String,
+    /// Where the line crosses `scaleID`
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) value: NumberOrDateString,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) xMax: NumberOrDateString,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) xMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) xScaleID: String,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) yMax: NumberOrDateString,
     #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
     pub(crate) yMin: NumberOrDateString,
-    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
-    pub(crate) yScaleID: NumberString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) yScaleID: String,
 }
-#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct LineAnnotationType;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct BoxAnnotation {
+    /// Whether the scale ranges are extended to fit the annotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) adjustScaleRange: Option<bool>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) backgroundColor: String,
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -634,21 +1397,217 @@ pub struct BoxAnnotation {
     pub(crate) borderDash: Vec<NumberString>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) borderWidth: Option<NumberStringOrT<Border>>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) click: FnWithArgs<2>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) display: Option<FnWithArgsOrT<1, BoolString>>,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) drawTime: String,
-    #[serde(default, rename = "type")]
-    pub(crate) r#type: BoxAnnotationType,
-    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
-    pub(crate) xMax: NumberString,
-    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
-    pub(crate) xMin: NumberString,
-    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
-    pub(crate) yMax: NumberString,
-    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
-    pub(crate) yMin: NumberString,
-}
-#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct BoxAnnotationType;
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) enter: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) leave: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xMax: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) xScaleID: String,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yMax: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) yScaleID: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PointAnnotation {
+    /// Whether the scale ranges are extended to fit the annotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) adjustScaleRange: Option<bool>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) backgroundColor: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderColor: String,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) borderDash: Vec<NumberString>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) borderDashOffset: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) borderWidth: NumberString,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) click: FnWithArgs<2>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) display: Option<FnWithArgsOrT<1, BoolString>>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) drawTime: String,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) enter: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) leave: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) pointStyle: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) radius: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) rotation: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) xAdjust: NumberString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xMax: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) xScaleID: String,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xValue: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) yAdjust: NumberString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yMax: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) yScaleID: String,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yValue: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) z: NumberString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PolygonAnnotation {
+    /// Whether the scale ranges are extended to fit the annotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) adjustScaleRange: Option<bool>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) backgroundColor: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderCapStyle: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderColor: String,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) borderDash: Vec<NumberString>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) borderDashOffset: NumberString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderJoinStyle: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) borderWidth: NumberString,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) click: FnWithArgs<2>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) display: Option<FnWithArgsOrT<1, BoolString>>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) drawTime: String,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) enter: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) leave: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) radius: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) rotation: NumberString,
+    /// The number of sides, `3` for a triangle
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) sides: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) xAdjust: NumberString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xMax: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) xScaleID: String,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xValue: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) yAdjust: NumberString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yMax: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) yScaleID: String,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yValue: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) z: NumberString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EllipseAnnotation {
+    /// Whether the scale ranges are extended to fit the annotation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) adjustScaleRange: Option<bool>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) backgroundColor: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderColor: String,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub(crate) borderDash: Vec<NumberString>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) borderDashOffset: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) borderWidth: NumberString,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) click: FnWithArgs<2>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) display: Option<FnWithArgsOrT<1, BoolString>>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) drawTime: String,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) enter: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) leave: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) label: Option<LabelAnnotation>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) rotation: NumberString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xMax: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) xMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) xScaleID: String,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yMax: NumberOrDateString,
+    #[serde(skip_serializing_if = "NumberOrDateString::is_empty", default)]
+    pub(crate) yMin: NumberOrDateString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) yScaleID: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) z: NumberString,
+}
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ScaleTime {
@@ -680,43 +1639,140 @@ pub struct DisplayFormats {
 pub struct ScaleTicks {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) autoSkip: Option<bool>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) autoSkipPadding: NumberString,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) align: String,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) backdropColor: FnWithArgsOrT<1, String>,
+    #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
+    // the skip_deserializing needed because chartjs sets a default with a different type
+    pub(crate) backdropPadding: Option<NumberStringOrT<Padding>>,
     #[serde(
         skip_serializing_if = "FnWithArgs::is_empty",
         default,
         skip_deserializing // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
     )]
     pub(crate) callback: FnWithArgs<3>,
-    #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) color: String,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) color: FnWithArgsOrT<1, String>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) count: NumberString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) crossAlign: String,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) font: Option<FnWithArgsOrT<1, Font>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) font: Option<Font>,
+    pub(crate) format: Option<NumberFormat>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) includeBounds: Option<bool>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) labelOffset: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) major: Option<MajorTicks>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) maxRotation: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) maxTicksLimit: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) minRotation: NumberString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) mirror: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", skip_deserializing)]
     // the skip_deserializing needed because chartjs sets a default with a different type
     pub(crate) padding: Option<Padding>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) precision: NumberString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) sampleSize: NumberString,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) showLabelBackdrop: Option<FnWithArgsOrT<1, BoolString>>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) stepSize: NumberString,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) textStrokeColor: FnWithArgsOrT<1, String>,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) textStrokeWidth: FnWithArgsOrT<1, NumberString>,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) z: NumberString,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MajorTicks {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enabled: Option<bool>,
+}
+
+/// The `Intl.NumberFormat` options used to format numeric (`linear` and `logarithmic`) tick labels
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NumberFormat {
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) compactDisplay: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) currency: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) maximumFractionDigits: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) maximumSignificantDigits: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) minimumFractionDigits: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) minimumSignificantDigits: NumberString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) notation: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) style: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) unit: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) useGrouping: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Title {
+    /// One of `start`, `center` or `end`
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) align: String,
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) color: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) display: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fullSize: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) padding: Option<NumberStringOrT<Padding>>,
+    /// One of `top`, `left`, `bottom` or `right`
     #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) text: String,
+    pub(crate) position: String,
+    /// A single line, or each line of the title
+    #[serde(skip_serializing_if = "TitleText::is_empty", default)]
+    pub(crate) text: TitleText,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -796,7 +1852,7 @@ pub struct LineElementConfiguration {
     #[serde(skip_serializing_if = "String::is_empty", default)]
     pub(crate) cubicInterpolationMode: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) fill: Option<bool>,
+    pub(crate) fill: Option<Fill>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -815,42 +1871,109 @@ pub struct PointElementConfiguration {
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct DataLabels {
-    #[serde(skip_serializing_if = "FnWithArgsOrT::is_empty", default)]
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
     pub(crate) align: FnWithArgsOrT<1, String>,
-    #[serde(skip_serializing_if = "FnWithArgsOrT::is_empty", default)]
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
     pub(crate) anchor: FnWithArgsOrT<1, String>,
-    #[serde(skip_serializing_if = "FnWithArgsOrT::is_empty", default)]
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
     pub(crate) backgroundColor: FnWithArgsOrT<1, String>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) borderColor: String,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) borderRadius: NumberString,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) borderWidth: NumberString,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) clamp: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) clip: Option<bool>,
-    #[serde(skip_serializing_if = "String::is_empty", default)]
-    pub(crate) color: String,
-    #[serde(skip_serializing_if = "FnWithArgsOrT::is_empty", default)]
-    pub(crate) display: FnWithArgsOrT<1, BoolString>,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) color: FnWithArgsOrT<1, String>,
+    /// Unset so entries in `labels` inherit it
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) display: Option<FnWithArgsOrT<1, BoolString>>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) drawTime: NumberString,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) font: Option<Font>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) font: Option<FnWithArgsOrT<1, Font>>,
     #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
     // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
     pub(crate) formatter: FnWithArgs<2>,
-    #[serde(skip_serializing_if = "FnWithArgsOrT::is_empty", default)]
+    /// Several labels per element, keyed by name, each overriding the options around it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) labels: Option<BTreeMap<String, DataLabels>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) listeners: Option<DataLabelsListeners>,
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
     // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
     pub(crate) offset: FnWithArgsOrT<1, NumberString>,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) opacity: NumberString,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) padding: Option<Padding>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) rotation: Option<i16>,
+    /// In degrees
+    #[serde(
+        skip_serializing_if = "FnWithArgsOrT::is_empty",
+        default,
+        deserialize_with = "default_on_error"
+    )]
+    pub(crate) rotation: FnWithArgsOrT<1, NumberString>,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) textAlign: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) textShadowBlur: NumberString,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) textShadowColor: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub(crate) textStrokeColor: String,
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) textStrokeWidth: NumberString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) z: NumberString,
 }
 
+/// See <https://chartjs-plugin-datalabels.netlify.app/guide/events.html>
+#[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DataLabelsListeners {
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) click: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) enter: FnWithArgs<2>,
+    #[serde(skip_serializing_if = "FnWithArgs::is_empty", skip_deserializing)]
+    // FnWithArgs can't deser right now, might be solved in the future with a fancy serde deserializer
+    pub(crate) leave: FnWithArgs<2>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Border {
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
@@ -872,8 +1995,14 @@ pub struct Padding {
     pub(crate) right: NumberString,
     #[serde(skip_serializing_if = "NumberString::is_empty", default)]
     pub(crate) top: NumberString,
-}
-
+    /// Shorthand for `left` and `right`
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) x: NumberString,
+    /// Shorthand for `top` and `bottom`
+    #[serde(skip_serializing_if = "NumberString::is_empty", default)]
+    pub(crate) y: NumberString,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Font {
     #[serde(skip_serializing_if = "String::is_empty", default)]
@@ -916,18 +2045,66 @@ impl DatasetTrait for Vec<SinglePointDataset> {
         vec.dedup();
         vec
     }
+
+    fn check_log_axes(
+        &mut self,
+        log_axes: &HashSet<String>,
+        index_axis: &str,
+        policy: &LogAxisPolicy,
+    ) -> Vec<LogAxisViolation> {
+        self.iter_mut()
+            .enumerate()
+            .flat_map(|(dataset, spd)| {
+                let index_axis = Some(spd.indexAxis.as_str())
+                    .filter(|axis| !axis.is_empty())
+                    .unwrap_or(index_axis);
+                let axis = match index_axis {
+                    "y" => axis_id(&spd.xAxisID, "x"),
+                    _ => axis_id(&spd.yAxisID, "y"),
+                };
+                if !log_axes.contains(&axis) {
+                    return Vec::new();
+                }
+
+                spd.data
+                    .iter_mut()
+                    .enumerate()
+                    .filter(|(_, value)| {
+                        value
+                            .to_string()
+                            .parse::<f64>()
+                            .is_ok_and(|number| number <= 0.0)
+                    })
+                    .map(|(index, value)| {
+                        let violation = LogAxisViolation {
+                            dataset,
+                            label: spd.label.clone(),
+                            axis: axis.clone(),
+                            index,
+                            value: value.clone(),
+                        };
+                        if let LogAxisPolicy::Clamp(min) = policy {
+                            *value = min.clone();
+                        }
+                        violation
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 }
 impl DatasetTrait for Vec<XYDataset> {
     fn labels(self) -> Vec<NumberOrDateString> {
         let mut vec = self
             .into_iter()
             .filter_map(|xyd| {
+                let (x_key, _) = Parsing::axis_keys(&xyd.parsing);
                 let data = xyd.data.0.as_array()?;
                 // gloo_console::console_dbg!(&data);
                 let keys = data
                     .iter()
                     .filter_map(|xy| xy.as_object())
-                    .filter_map(|obj| obj.get("x"))
+                    .filter_map(|obj| obj.get(x_key))
                     .filter_map(|x| {
                         x.as_str()
                             .map(|s| s.to_string())
@@ -945,6 +2122,28 @@ impl DatasetTrait for Vec<XYDataset> {
         vec.dedup();
         vec
     }
+
+    fn check_log_axes(
+        &mut self,
+        log_axes: &HashSet<String>,
+        index_axis: &str,
+        policy: &LogAxisPolicy,
+    ) -> Vec<LogAxisViolation> {
+        self.iter_mut()
+            .enumerate()
+            .flat_map(|(dataset, xyd)| {
+                check_xy_log_axes(
+                    (dataset, &xyd.label),
+                    &mut xyd.data,
+                    Parsing::axis_keys(&xyd.parsing),
+                    (&xyd.xAxisID, &xyd.yAxisID),
+                    index_axis,
+                    log_axes,
+                    policy,
+                )
+            })
+            .collect()
+    }
 }
 impl DatasetTrait for Vec<FloatingDataset> {
     fn labels(self) -> Vec<NumberOrDateString> {
@@ -957,15 +2156,693 @@ impl DatasetTrait for Vec<FloatingDataset> {
         vec.dedup();
         vec
     }
+
+    fn check_log_axes(
+        &mut self,
+        log_axes: &HashSet<String>,
+        index_axis: &str,
+        policy: &LogAxisPolicy,
+    ) -> Vec<LogAxisViolation> {
+        self.iter_mut()
+            .enumerate()
+            .flat_map(|(dataset, fd)| {
+                check_xy_log_axes(
+                    (dataset, &fd.label),
+                    &mut fd.data,
+                    Parsing::axis_keys(&fd.parsing),
+                    (&fd.xAxisID, &fd.yAxisID),
+                    index_axis,
+                    log_axes,
+                    policy,
+                )
+            })
+            .collect()
+    }
+}
+/// Chart.js binds datasets without an explicit axis id to the default `x` and `y` scales
+fn axis_id(id: &str, default: &str) -> String {
+    if id.is_empty() { default } else { id }.to_string()
+}
+fn check_xy_log_axes(
+    (dataset, label): (usize, &str),
+    data: &mut DatasetData,
+    (x_key, y_key): (&str, &str),
+    (x_axis_id, y_axis_id): (&str, &str),
+    index_axis: &str,
+    log_axes: &HashSet<String>,
+    policy: &LogAxisPolicy,
+) -> Vec<LogAxisViolation> {
+    // horizontal charts plot their values on the x axis
+    let horizontal = index_axis == "y";
+    [
+        (x_key, horizontal, axis_id(x_axis_id, "x")),
+        (y_key, !horizontal, axis_id(y_axis_id, "y")),
+    ]
+    .into_iter()
+    .filter(|(_, _, axis)| log_axes.contains(axis))
+    .flat_map(|(key, is_value_axis, axis)| {
+        data.check_log_axis(key, is_value_axis, policy)
+            .into_iter()
+            .map(move |(index, value)| LogAxisViolation {
+                dataset,
+                label: label.to_string(),
+                axis: axis.clone(),
+                index,
+                value,
+            })
+            .collect::<Vec<_>>()
+    })
+    .collect()
+}
+impl Parsing {
+    /// The keys Chart.js reads x and y values from, defaulting to `x` and `y`
+    pub(crate) fn axis_keys(parsing: &Option<Parsing>) -> (&str, &str) {
+        match parsing {
+            Some(Parsing::Keys(keys)) => (
+                Some(keys.xAxisKey.as_str())
+                    .filter(|k| !k.is_empty())
+                    .unwrap_or("x"),
+                Some(keys.yAxisKey.as_str())
+                    .filter(|k| !k.is_empty())
+                    .unwrap_or("y"),
+            ),
+            _ => ("x", "y"),
+        }
+    }
+}
+impl From<bool> for Fill {
+    fn from(fill: bool) -> Self {
+        if fill {
+            Self::Origin
+        } else {
+            Self::Disabled
+        }
+    }
+}
+impl From<usize> for Fill {
+    fn from(index: usize) -> Self {
+        Self::Dataset(index)
+    }
+}
+impl From<FillTarget> for Fill {
+    fn from(target: FillTarget) -> Self {
+        Self::Target(target)
+    }
+}
+impl std::str::FromStr for Fill {
+    type Err = String;
+
+    /// Reads a Chart.js fill string, numbers that aren't a dataset index or relative fill to that value
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "false" => Ok(Self::Disabled),
+            "true" | "origin" => Ok(Self::Origin),
+            "start" => Ok(Self::Start),
+            "end" => Ok(Self::End),
+            "stack" => Ok(Self::Stack),
+            "shape" => Ok(Self::Shape),
+            s if s.starts_with(['+', '-']) => s
+                .parse()
+                .map(Self::Relative)
+                .map_err(|_| format!("invalid relative fill `{s}`")),
+            s => match (s.parse(), s.parse::<f64>()) {
+                (Ok(index), _) => Ok(Self::Dataset(index)),
+                (_, Ok(value)) if value.is_finite() => Ok(Self::Value(s.into())),
+                _ => Err(format!("invalid fill `{s}`")),
+            },
+        }
+    }
+}
+/// Invalid fills log a console warning and disable the fill
+impl From<&str> for Fill {
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or_else(|e: String| {
+            gloo_console::warn!(e);
+            Self::Disabled
+        })
+    }
+}
+impl From<String> for Fill {
+    fn from(s: String) -> Self {
+        s.as_str().into()
+    }
+}
+impl ChartOptions {
+    /// Calls `f` with each click on the chart, and the elements under it, bridged through [`FnWithArgs::rust_closure`]
+    ///
+    /// ```rust no_run
+    /// ChartOptions::new().on_click_closure(|event: Result<ChartEvent<NaiveDate, f64>, _>| {
+    ///     if let Some(element) = event.ok().and_then(|e| e.elements.into_iter().next()) {
+    ///         drill_down(element.dataset_index, element.coordinate.x);
+    ///     }
+    /// })
+    /// ```
+    #[track_caller]
+    pub fn on_click_closure<T, TE, U, UE, F>(mut self, f: F) -> Self
+    where
+        T: std::str::FromStr<Err = TE>,
+        U: std::str::FromStr<Err = UE>,
+        TE: std::error::Error + Sync + Send + 'static,
+        UE: std::error::Error + Sync + Send + 'static,
+        F: Fn(Result<ChartEvent<T, U>, CoordinateError>) + 'static,
+    {
+        self.onClick = chart_event_closure(f);
+        self
+    }
+
+    /// Calls `f` as the pointer moves over the chart, see [`ChartOptions::on_click_closure`]
+    #[track_caller]
+    pub fn on_hover_closure<T, TE, U, UE, F>(mut self, f: F) -> Self
+    where
+        T: std::str::FromStr<Err = TE>,
+        U: std::str::FromStr<Err = UE>,
+        TE: std::error::Error + Sync + Send + 'static,
+        UE: std::error::Error + Sync + Send + 'static,
+        F: Fn(Result<ChartEvent<T, U>, CoordinateError>) + 'static,
+    {
+        self.onHover = chart_event_closure(f);
+        self
+    }
+
+    /// Calls `f` with the chart's new size whenever it is resized, bridged through [`FnWithArgs::rust_closure`]
+    #[track_caller]
+    pub fn on_resize_closure<F: Fn(Result<ChartResize, CoordinateError>) + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.onResize =
+            FnWithArgs::<2>::new()
+                .args(["chart", "size"])
+                .rust_closure(move |chart, size| {
+                    f(ChartResize::parse(chart, size));
+                    wasm_bindgen::JsValue::UNDEFINED
+                });
+        self
+    }
+}
+impl PluginLegend {
+    /// Replaces the default show/hide toggle, bridged through [`FnWithArgs::rust_closure`]
+    ///
+    /// ```rust no_run
+    /// PluginLegend::new().on_click_closure(|event| {
+    ///     if let Ok(event) = event {
+    ///         event.isolate();
+    ///     }
+    /// })
+    /// ```
+    #[track_caller]
+    pub fn on_click_closure<F: Fn(Result<LegendEvent, CoordinateError>) + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.onClick = legend_event_closure(f);
+        self
+    }
+
+    /// Calls `f` when the pointer moves onto a legend item
+    #[track_caller]
+    pub fn on_hover_closure<F: Fn(Result<LegendEvent, CoordinateError>) + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.onHover = legend_event_closure(f);
+        self
+    }
+
+    /// Calls `f` when the pointer leaves a legend item
+    #[track_caller]
+    pub fn on_leave_closure<F: Fn(Result<LegendEvent, CoordinateError>) + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.onLeave = legend_event_closure(f);
+        self
+    }
+}
+
+impl TooltipPlugin {
+    /// Replaces the canvas tooltip with an HTML one, `f` returns the tooltip's inner HTML.
+    ///
+    /// The tooltip is a `div` with the id `<canvas id>-tooltip`, added next to the canvas
+    /// and positioned at the caret, so the canvas' parent should be `position: relative`.
+    /// It is removed when the tooltip hides, and on errors parsing the [`TooltipModel`].
+    ///
+    /// ```rust no_run
+    /// TooltipPlugin::new().external_html(|model: &TooltipModel<NaiveDate, f64>| {
+    ///     let rows = model
+    ///         .data_points
+    ///         .iter()
+    ///         .map(|point| format!("<li>{}: {}</li>", point.dataset.label, point.raw.y))
+    ///         .collect::<String>();
+    ///     format!("<h4>{}</h4><ul>{rows}</ul>", model.title.join(" "))
+    /// })
+    /// ```
+    #[track_caller]
+    pub fn external_html<T, TE, U, UE, F>(mut self, f: F) -> Self
+    where
+        T: std::str::FromStr<Err = TE> + Debug,
+        U: std::str::FromStr<Err = UE> + Debug,
+        TE: std::error::Error + Sync + Send + 'static,
+        UE: std::error::Error + Sync + Send + 'static,
+        F: Fn(&TooltipModel<T, U>) -> String + 'static,
+    {
+        self.enabled = Some(false);
+        self.external = FnWithArgs::<1>::new()
+            .args(["context"])
+            .rust_closure(move |context| {
+                match TooltipModel::<T, U>::parse(&context) {
+                    Ok(model) if model.opacity == 0.0 => {
+                        crate::utils::remove_html_tooltip(&model.chart_id)
+                    }
+                    Ok(model) => crate::utils::show_html_tooltip(
+                        &model.chart_id,
+                        (model.caret_x, model.caret_y),
+                        &f(&model),
+                    ),
+                    Err(e) => {
+                        gloo_console::error!(format!("Error parsing tooltip: {e}"));
+                        if let Some(id) = crate::utils::canvas_id(
+                            &js_sys::Reflect::get(&context, &"chart".into()).unwrap_or_default(),
+                        ) {
+                            crate::utils::remove_html_tooltip(&id)
+                        }
+                    }
+                }
+                wasm_bindgen::JsValue::UNDEFINED
+            });
+        self
+    }
+}
+
+impl PanOptions {
+    /// Calls `f` with the new scale ranges once panning stops, bridged through [`FnWithArgs::rust_closure`]
+    #[track_caller]
+    pub fn on_pan_complete_closure<F: Fn(Result<ScaleRanges, CoordinateError>) + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.onPanComplete = scale_ranges_closure(f);
+        self
+    }
+}
+impl ZoomOptions {
+    /// Calls `f` with the new scale ranges once zooming stops, bridged through [`FnWithArgs::rust_closure`]
+    ///
+    /// ```rust no_run
+    /// ZoomOptions::new().on_zoom_complete_closure(|ranges| {
+    ///     if let Some(x) = ranges.ok().and_then(|r| r.scales.get("x").copied()) {
+    ///         load_detail(x.min, x.max);
+    ///     }
+    /// })
+    /// ```
+    #[track_caller]
+    pub fn on_zoom_complete_closure<F: Fn(Result<ScaleRanges, CoordinateError>) + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.onZoomComplete = scale_ranges_closure(f);
+        self
+    }
+}
+
+#[track_caller]
+fn scale_ranges_closure<F: Fn(Result<ScaleRanges, CoordinateError>) + 'static>(
+    f: F,
+) -> FnWithArgs<1> {
+    FnWithArgs::<1>::new()
+        .args(["context"])
+        .rust_closure(move |context| {
+            f(ScaleRanges::parse(context));
+            wasm_bindgen::JsValue::UNDEFINED
+        })
+}
+
+macro_rules! listener_closures {
+    ($event:ty, $target:literal => $($listeners:ty),*) => {$(
+        impl $listeners {
+            #[doc = concat!("Calls `f` when ", $target, " is clicked")]
+            #[track_caller]
+            pub fn on_click_closure<F: Fn(Result<$event, CoordinateError>) + 'static>(
+                mut self,
+                f: F,
+            ) -> Self {
+                self.click = listener_closure(<$event>::parse, f);
+                self
+            }
+
+            #[doc = concat!("Calls `f` when the pointer moves onto ", $target)]
+            #[track_caller]
+            pub fn on_enter_closure<F: Fn(Result<$event, CoordinateError>) + 'static>(
+                mut self,
+                f: F,
+            ) -> Self {
+                self.enter = listener_closure(<$event>::parse, f);
+                self
+            }
+
+            #[doc = concat!("Calls `f` when the pointer leaves ", $target)]
+            #[track_caller]
+            pub fn on_leave_closure<F: Fn(Result<$event, CoordinateError>) + 'static>(
+                mut self,
+                f: F,
+            ) -> Self {
+                self.leave = listener_closure(<$event>::parse, f);
+                self
+            }
+        }
+    )*};
+}
+listener_closures!(DataLabelEvent, "a label" => DataLabelsListeners);
+listener_closures!(
+    AnnotationEvent, "the annotation" =>
+    BoxAnnotation,
+    EllipseAnnotation,
+    LabelAnnotation,
+    LineAnnotation,
+    PointAnnotation,
+    PolygonAnnotation
+);
+
+/// Bridges the `(context, event)` listeners of chartjs-plugin-annotation and chartjs-plugin-datalabels
+#[track_caller]
+fn listener_closure<E: 'static, F>(
+    parse: fn(wasm_bindgen::JsValue, wasm_bindgen::JsValue) -> Result<E, CoordinateError>,
+    f: F,
+) -> FnWithArgs<2>
+where
+    F: Fn(Result<E, CoordinateError>) + 'static,
+{
+    FnWithArgs::<2>::new()
+        .args(["context", "event"])
+        .rust_closure(move |context, event| {
+            f(parse(context, event));
+            wasm_bindgen::JsValue::UNDEFINED
+        })
+}
+
+#[track_caller]
+fn legend_event_closure<F: Fn(Result<LegendEvent, CoordinateError>) + 'static>(
+    f: F,
+) -> FnWithArgs<3> {
+    FnWithArgs::<3>::new()
+        .args(["event", "legendItem", "legend"])
+        .rust_closure(move |event, item, legend| {
+            f(LegendEvent::parse(event, item, legend));
+            wasm_bindgen::JsValue::UNDEFINED
+        })
+}
+
+#[track_caller]
+fn chart_event_closure<T, TE, U, UE, F>(f: F) -> FnWithArgs<3>
+where
+    T: std::str::FromStr<Err = TE>,
+    U: std::str::FromStr<Err = UE>,
+    TE: std::error::Error + Sync + Send + 'static,
+    UE: std::error::Error + Sync + Send + 'static,
+    F: Fn(Result<ChartEvent<T, U>, CoordinateError>) + 'static,
+{
+    FnWithArgs::<3>::new()
+        .args(["event", "elements", "chart"])
+        .rust_closure(move |event, elements, chart| {
+            f(ChartEvent::parse(event, elements, chart));
+            wasm_bindgen::JsValue::UNDEFINED
+        })
+}
+impl Animation {
+    /// Calls `f` on every animation frame, bridged through [`FnWithArgs::rust_closure`]
+    #[track_caller]
+    pub fn on_progress_closure<F: Fn(Result<AnimationProgress, CoordinateError>) + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.onProgress =
+            FnWithArgs::<1>::new()
+                .args(["animation"])
+                .rust_closure(move |animation| {
+                    f(AnimationProgress::parse(animation));
+                    wasm_bindgen::JsValue::UNDEFINED
+                });
+        self
+    }
+
+    /// Calls `f` once the animation has finished, bridged through [`FnWithArgs::rust_closure`]
+    #[track_caller]
+    pub fn on_complete_closure<F: Fn(Result<AnimationProgress, CoordinateError>) + 'static>(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.onComplete =
+            FnWithArgs::<1>::new()
+                .args(["animation"])
+                .rust_closure(move |animation| {
+                    f(AnimationProgress::parse(animation));
+                    wasm_bindgen::JsValue::UNDEFINED
+                });
+        self
+    }
+}
+impl TooltipPosition {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TooltipPosition::Average => "average",
+            TooltipPosition::Nearest => "nearest",
+            TooltipPosition::Custom(name) => name,
+        }
+    }
+}
+impl From<&str> for TooltipPosition {
+    fn from(s: &str) -> Self {
+        match s {
+            "average" => Self::Average,
+            "nearest" => Self::Nearest,
+            s => Self::Custom(s.to_string()),
+        }
+    }
+}
+impl From<String> for TooltipPosition {
+    fn from(s: String) -> Self {
+        s.as_str().into()
+    }
+}
+impl UpdateMode {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            UpdateMode::Default => None,
+            UpdateMode::None => Some("none"),
+            UpdateMode::Active => Some("active"),
+            UpdateMode::Show => Some("show"),
+            UpdateMode::Hide => Some("hide"),
+            UpdateMode::Reset => Some("reset"),
+            UpdateMode::Resize => Some("resize"),
+            UpdateMode::Custom(mode) => Some(mode),
+        }
+    }
+}
+/// `true` animates with the default animation, `false` skips animations
+impl From<bool> for UpdateMode {
+    fn from(animate: bool) -> Self {
+        if animate {
+            Self::Default
+        } else {
+            Self::None
+        }
+    }
+}
+impl From<&str> for UpdateMode {
+    fn from(s: &str) -> Self {
+        match s {
+            "" => Self::Default,
+            "none" => Self::None,
+            "active" => Self::Active,
+            "show" => Self::Show,
+            "hide" => Self::Hide,
+            "reset" => Self::Reset,
+            "resize" => Self::Resize,
+            s => Self::Custom(s.to_string()),
+        }
+    }
+}
+impl From<String> for UpdateMode {
+    fn from(s: String) -> Self {
+        s.as_str().into()
+    }
+}
+impl<T: Into<NumberString>> From<T> for SpanGaps {
+    fn from(value: T) -> Self {
+        // `bool`s go through their `Display` impl too
+        let value = value.into();
+        match value.to_string().as_str() {
+            "true" => Self::Bool(true),
+            "false" => Self::Bool(false),
+            _ => Self::MaxGap(value),
+        }
+    }
+}
+impl SpanGaps {
+    /// The largest gap that is still spanned on a time scale
+    pub fn max_gap(max_gap: std::time::Duration) -> Self {
+        Self::MaxGap(max_gap.as_millis().into())
+    }
+}
+impl From<bool> for Stepped {
+    fn from(stepped: bool) -> Self {
+        if stepped {
+            Self::Before
+        } else {
+            Self::Disabled
+        }
+    }
+}
+impl std::str::FromStr for Stepped {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "false" => Ok(Self::Disabled),
+            "true" | "before" => Ok(Self::Before),
+            "after" => Ok(Self::After),
+            "middle" => Ok(Self::Middle),
+            s => Err(format!("invalid stepped mode `{s}`")),
+        }
+    }
+}
+/// Invalid modes log a console warning and disable stepping
+impl From<&str> for Stepped {
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or_else(|e: String| {
+            gloo_console::warn!(e);
+            Self::Disabled
+        })
+    }
+}
+impl From<String> for Stepped {
+    fn from(s: String) -> Self {
+        s.as_str().into()
+    }
+}
+impl From<ParsingKeys> for Parsing {
+    fn from(keys: ParsingKeys) -> Self {
+        Self::Keys(keys)
+    }
+}
+impl From<bool> for Parsing {
+    fn from(enabled: bool) -> Self {
+        if enabled {
+            Self::Keys(ParsingKeys::default())
+        } else {
+            Self::Disabled
+        }
+    }
+}
+//
+/// The content of a [`LabelAnnotation`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LabelContent {
+    Text(Vec<String>),
+    Image(LabelImage),
+}
+impl Default for LabelContent {
+    fn default() -> Self {
+        Self::Text(Vec::new())
+    }
+}
+impl crate::ChartJsRsObject for LabelContent {
+    fn is_empty(&self) -> bool {
+        match self {
+            LabelContent::Text(lines) => lines.is_empty(),
+            LabelContent::Image(image) => image.src.is_empty(),
+        }
+    }
+}
+impl From<&str> for LabelContent {
+    fn from(value: &str) -> Self {
+        Self::Text(Vec::from([value.to_string()]))
+    }
+}
+impl From<String> for LabelContent {
+    fn from(value: String) -> Self {
+        Self::Text(Vec::from([value]))
+    }
+}
+impl<S: Into<String>> From<Vec<S>> for LabelContent {
+    fn from(value: Vec<S>) -> Self {
+        Self::Text(value.into_iter().map(Into::into).collect())
+    }
+}
+impl<S: Into<String>, const N: usize> From<[S; N]> for LabelContent {
+    fn from(value: [S; N]) -> Self {
+        Self::Text(value.into_iter().map(Into::into).collect())
+    }
+}
+impl From<LabelImage> for LabelContent {
+    fn from(value: LabelImage) -> Self {
+        Self::Image(value)
+    }
+}
+impl<const N: usize, T: Into<LabelContent>> From<T> for FnWithArgsOrT<N, LabelContent> {
+    fn from(value: T) -> Self {
+        Self::T(value.into())
+    }
+}
+impl LabelImage {
+    pub fn from_url(url: impl Into<String>) -> Self {
+        Self::new().src(url)
+    }
+
+    /// Embeds the image as a `data:` url, eg. `LabelImage::from_bytes(include_bytes!("deploy.png"), "image/png")`
+    pub fn from_bytes(bytes: &[u8], mime_type: &str) -> Self {
+        Self::new().src(format!("data:{mime_type};base64,{}", base64_encode(bytes)))
+    }
+}
+impl LabelAnnotation {
+    /// Sets `content` to lines of text returned by `f`, eg. the version of a deploy
+    #[track_caller]
+    pub fn content_closure<
+        F: Fn(Result<AnnotationContext, CoordinateError>) -> Vec<String> + 'static,
+    >(
+        mut self,
+        f: F,
+    ) -> Self {
+        self.content = FnWithArgs::<1>::new()
+            .args(["context"])
+            .rust_closure(move |context| {
+                f(AnnotationContext::parse(context))
+                    .into_iter()
+                    .map(wasm_bindgen::JsValue::from)
+                    .collect::<js_sys::Array>()
+                    .into()
+            })
+            .into();
+        self
+    }
+}
+impl LineAnnotation {
+    /// Hides the label until the pointer is over the line.
+    /// This sets the `enter` and `leave` handlers, replacing any set before.
+    pub fn label_display_on_hover(mut self) -> Self {
+        self.label = Some(self.label.unwrap_or_default().display(false));
+        self.enter = FnWithArgs::<2>::new()
+            .args(["context", "event"])
+            .js_body("context.element.label.options.display = true;")
+            .js_return_value("true");
+        self.leave = FnWithArgs::<2>::new()
+            .args(["context", "event"])
+            .js_body("context.element.label.options.display = false;")
+            .js_return_value("true");
+        self
+    }
 }
 //
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
-#[serde(untagged)]
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
 pub enum Annotation {
     Box(BoxAnnotation),
-    Line(LineAnnotation),
+    Ellipse(EllipseAnnotation),
     Label(LabelAnnotation),
+    Line(LineAnnotation),
+    Point(PointAnnotation),
+    Polygon(PolygonAnnotation),
 }
 
 impl Default for Annotation {
@@ -988,6 +2865,21 @@ impl From<LabelAnnotation> for Annotation {
         Self::Label(value)
     }
 }
+impl From<PointAnnotation> for Annotation {
+    fn from(value: PointAnnotation) -> Self {
+        Self::Point(value)
+    }
+}
+impl From<PolygonAnnotation> for Annotation {
+    fn from(value: PolygonAnnotation) -> Self {
+        Self::Polygon(value)
+    }
+}
+impl From<EllipseAnnotation> for Annotation {
+    fn from(value: EllipseAnnotation) -> Self {
+        Self::Ellipse(value)
+    }
+}
 //
 impl From<(NumberOrDateString, NumberString, Option<Value>)> for XYPoint {
     fn from((x, y, d): (NumberOrDateString, NumberString, Option<Value>)) -> Self {
@@ -1011,72 +2903,608 @@ impl PartialOrd for XYPoint {
     }
 }
 //
-impl Serialize for BoxAnnotationType {
+impl ScaleType {
+    fn as_str(&self) -> &str {
+        match self {
+            ScaleType::Category => "category",
+            ScaleType::Linear => "linear",
+            ScaleType::Logarithmic => "logarithmic",
+            ScaleType::RadialLinear => "radialLinear",
+            ScaleType::Time => "time",
+            ScaleType::TimeSeries => "timeseries",
+            ScaleType::Custom(custom) => custom,
+        }
+    }
+}
+impl From<&str> for ScaleType {
+    fn from(value: &str) -> Self {
+        match value {
+            "category" => ScaleType::Category,
+            "linear" => ScaleType::Linear,
+            "logarithmic" => ScaleType::Logarithmic,
+            "radialLinear" => ScaleType::RadialLinear,
+            "time" => ScaleType::Time,
+            "timeseries" => ScaleType::TimeSeries,
+            custom => ScaleType::Custom(custom.to_string()),
+        }
+    }
+}
+impl From<String> for ScaleType {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+//
+impl Serialize for ScaleType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str("box")
+        serializer.serialize_str(self.as_str())
+    }
+}
+//
+impl<'de> Deserialize<'de> for ScaleType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Into::into)
+    }
+}
+//
+impl<const N: usize> From<Font> for FnWithArgsOrT<N, Font> {
+    fn from(font: Font) -> Self {
+        Self::T(font)
     }
 }
 //
-impl Serialize for LineAnnotationType {
+impl ScaleTicks {
+    /// Draws major ticks (eg. the first tick of each month on a time scale) in `color`,
+    /// by replacing the tick `color` with a scriptable one, and enables major ticks
+    pub fn major_color(mut self, color: impl Into<String>) -> ScaleTicks {
+        let minor = match &self.color {
+            FnWithArgsOrT::T(minor) if !minor.is_empty() => serde_json::to_string(minor).unwrap(),
+            _ => "Chart.defaults.color".to_string(),
+        };
+        let major = serde_json::to_string(&color.into()).unwrap();
+
+        self.color = FnWithArgs::<1>::new()
+            .args(["context"])
+            .js_return_value(&format!(
+                "context.tick && context.tick.major ? {major} : {minor}"
+            ))
+            .into();
+        self.major = Some(MajorTicks {
+            enabled: Some(true),
+        });
+        self
+    }
+
+    /// Draws major ticks (eg. the first tick of each month on a time scale) in `font`,
+    /// by replacing the tick `font` with a scriptable one, and enables major ticks
+    pub fn major_font(mut self, font: impl Into<Font>) -> ScaleTicks {
+        let minor = match &self.font {
+            Some(FnWithArgsOrT::T(minor)) => serde_json::to_string(minor).unwrap(),
+            _ => "undefined".to_string(),
+        };
+        let major = serde_json::to_string(&font.into()).unwrap();
+
+        self.font = Some(
+            FnWithArgs::<1>::new()
+                .args(["context"])
+                .js_return_value(&format!(
+                    "context.tick && context.tick.major ? {major} : {minor}"
+                ))
+                .into(),
+        );
+        self.major = Some(MajorTicks {
+            enabled: Some(true),
+        });
+        self
+    }
+}
+//
+impl Serialize for Parsing {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str("line")
+        match self {
+            Parsing::Disabled => serializer.serialize_bool(false),
+            Parsing::Keys(keys) => keys.serialize(serializer),
+        }
+    }
+}
+//
+impl<'de> Deserialize<'de> for Parsing {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::Bool(enabled) => Ok(enabled.into()),
+            keys => ParsingKeys::deserialize(keys)
+                .map(Parsing::Keys)
+                .map_err(de::Error::custom),
+        }
     }
 }
 //
-impl Serialize for LabelAnnotationType {
+impl Serialize for Fill {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str("label")
+        match self {
+            Fill::Disabled => serializer.serialize_bool(false),
+            Fill::Origin => serializer.serialize_str("origin"),
+            Fill::Start => serializer.serialize_str("start"),
+            Fill::End => serializer.serialize_str("end"),
+            Fill::Stack => serializer.serialize_str("stack"),
+            Fill::Shape => serializer.serialize_str("shape"),
+            Fill::Dataset(index) => serializer.serialize_u64(*index as u64),
+            Fill::Relative(offset) => serializer.serialize_str(&format!("{offset:+}")),
+            Fill::Value(value) => serde_json::json!({ "value": value }).serialize(serializer),
+            Fill::Target(target) => target.serialize(serializer),
+        }
     }
 }
 //
-impl<'de> Deserialize<'de> for BoxAnnotationType {
+impl<'de> Deserialize<'de> for Fill {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        match String::deserialize(deserializer)?.to_lowercase().as_str() {
-            "box" => Ok(BoxAnnotationType),
-            other => Err(de::Error::custom(format!(
-                "`{other}` is not a valid BoxAnnotationType."
-            ))),
+        match Value::deserialize(deserializer)? {
+            Value::Bool(fill) => Ok(fill.into()),
+            Value::Number(index) => index
+                .as_u64()
+                .map(|index| Fill::Dataset(index as usize))
+                .ok_or_else(|| de::Error::custom("fill index must be a positive integer")),
+            Value::String(s) => s.parse().map_err(de::Error::custom),
+            Value::Object(mut obj) if !obj.contains_key("target") => {
+                let value = obj
+                    .remove("value")
+                    .ok_or_else(|| de::Error::custom("fill object needs a `value` or `target`"))?;
+                let value = value.to_string().trim_matches('"').to_string();
+                match value.parse::<f64>() {
+                    Ok(number) if number.is_finite() => Ok(Fill::Value(value.into())),
+                    _ => Err(de::Error::custom(format!("invalid fill value `{value}`"))),
+                }
+            }
+            target => FillTarget::deserialize(target)
+                .map(Fill::Target)
+                .map_err(de::Error::custom),
+        }
+    }
+}
+//
+impl Serialize for Stepped {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Stepped::Disabled => serializer.serialize_bool(false),
+            Stepped::Before => serializer.serialize_str("before"),
+            Stepped::After => serializer.serialize_str("after"),
+            Stepped::Middle => serializer.serialize_str("middle"),
         }
     }
 }
 //
-impl<'de> Deserialize<'de> for LineAnnotationType {
+impl<'de> Deserialize<'de> for Stepped {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        match String::deserialize(deserializer)?.to_lowercase().as_str() {
-            "line" => Ok(LineAnnotationType),
-            other => Err(de::Error::custom(format!(
-                "`{other}` is not a valid LineAnnotationType."
-            ))),
+        match Value::deserialize(deserializer)? {
+            Value::Bool(stepped) => Ok(stepped.into()),
+            Value::String(s) => s.parse().map_err(de::Error::custom),
+            other => Err(de::Error::custom(format!("invalid stepped mode: {other}"))),
         }
     }
 }
 //
-impl<'de> Deserialize<'de> for LabelAnnotationType {
+impl Serialize for TooltipPosition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+//
+impl<'de> Deserialize<'de> for TooltipPosition {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        match String::deserialize(deserializer)?.to_lowercase().as_str() {
-            "label" => Ok(LabelAnnotationType),
+        String::deserialize(deserializer).map(Into::into)
+    }
+}
+//
+//
+impl<'de> Deserialize<'de> for Annotation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // an internally tagged enum buffers every field, so drop the closures' functions first,
+        // their fields aren't deserialized anyway
+        let annotation = LossyValue::deserialize(deserializer)?.0;
+        let annotation_type = annotation
+            .get("type")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| de::Error::missing_field("type"))?;
+        match annotation_type.as_str() {
+            "box" => serde_json::from_value(annotation).map(Annotation::Box),
+            "ellipse" => serde_json::from_value(annotation).map(Annotation::Ellipse),
+            "label" => serde_json::from_value(annotation).map(Annotation::Label),
+            "line" => serde_json::from_value(annotation).map(Annotation::Line),
+            "point" => serde_json::from_value(annotation).map(Annotation::Point),
+            "polygon" => serde_json::from_value(annotation).map(Annotation::Polygon),
+            other => {
+                return Err(de::Error::unknown_variant(
+                    other,
+                    &["box", "ellipse", "label", "line", "point", "polygon"],
+                ))
+            }
+        }
+        .map_err(de::Error::custom)
+    }
+}
+//
+/// The field a rendered `Image` keeps its [`LabelImage`] in, so it can be read back
+pub(crate) const RENDERED_IMAGE_CONTENT: &str = "chartJsRsContent";
+impl Serialize for LabelContent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            LabelContent::Text(lines) => lines.serialize(serializer),
+            LabelContent::Image(image) => image.serialize(serializer),
+        }
+    }
+}
+//
+impl<'de> Deserialize<'de> for LabelContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::String(line) => Ok(LabelContent::Text(Vec::from([line]))),
+            Value::Array(lines) => serde_json::from_value(Value::Array(lines))
+                .map(LabelContent::Text)
+                .map_err(de::Error::custom),
+            // a rendered `Image` keeps its `LabelImage` in an enumerable field, other images have none,
+            // functions are left for `FnWithArgsOrT`
+            Value::Object(mut obj) if obj.contains_key(RENDERED_IMAGE_CONTENT) => {
+                serde_json::from_value(obj.remove(RENDERED_IMAGE_CONTENT).unwrap_or_default())
+                    .map(LabelContent::Image)
+                    .map_err(de::Error::custom)
+            }
+            Value::Object(obj) if obj.is_empty() => Ok(LabelContent::Image(LabelImage::default())),
+            Value::Object(obj) if obj.contains_key("src") => {
+                serde_json::from_value(Value::Object(obj))
+                    .map(LabelContent::Image)
+                    .map_err(de::Error::custom)
+            }
             other => Err(de::Error::custom(format!(
-                "`{other}` is not a valid LabelAnnotationType."
+                "`{other}` is not a valid LabelContent."
             ))),
         }
     }
 }
-//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn label_content_reads_back_a_rendered_image() {
+        // the enumerable fields of an `Image` after `Chart::rationalise_js` has loaded it
+        let rendered = json!({
+            "content": {RENDERED_IMAGE_CONTENT: {"src": "deploy.png", "width": 16}},
+            "xValue": 1,
+        });
+        let label: LabelAnnotation = serde_json::from_value(rendered).unwrap();
+
+        let image = LabelImage::from_url("deploy.png").width(16);
+        assert_eq!(
+            label.content,
+            FnWithArgsOrT::T(LabelContent::Image(image.clone()))
+        );
+        assert_eq!(
+            serde_json::to_value(&label).unwrap()["content"],
+            serde_json::to_value(&image).unwrap()
+        );
+    }
+
+    #[test]
+    fn annotations_round_trip() {
+        let label = LabelAnnotation::new()
+            .content(["v1.2", "deployed"])
+            .x_value(3)
+            .y_value(10)
+            .color("red");
+        let annotations: [Annotation; 6] = [
+            BoxAnnotation::new()
+                .x_min(1)
+                .x_max(2)
+                .background_color("blue")
+                .display(false)
+                .into(),
+            EllipseAnnotation::new()
+                .y_min(-1)
+                .y_max(1)
+                .rotation(45)
+                .label(label.clone())
+                .into(),
+            label.clone().into(),
+            LineAnnotation::new()
+                .scale_id("y")
+                .value(5)
+                .border_dash([2, 2])
+                .label(label)
+                .into(),
+            PointAnnotation::new()
+                .x_value("2024-01-01")
+                .y_value(4)
+                .radius(6)
+                .point_style("star")
+                .into(),
+            PolygonAnnotation::new()
+                .x_value(1)
+                .y_value(1)
+                .sides(5)
+                .border_join_style("round")
+                .into(),
+        ];
+
+        for annotation in annotations {
+            let json = serde_json::to_value(&annotation).unwrap();
+            assert!(json["type"].is_string(), "{json}");
+            let read_back: Annotation = serde_json::from_value(json).unwrap();
+            assert_eq!(read_back, annotation);
+        }
+    }
+
+    #[test]
+    fn annotation_rejects_an_unknown_type() {
+        assert!(serde_json::from_value::<Annotation>(json!({"type": "arrow"})).is_err());
+        assert!(serde_json::from_value::<Annotation>(json!({"xMin": 1})).is_err());
+    }
+
+    fn log_axes(ids: &[&str]) -> HashSet<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    fn violations(found: &[LogAxisViolation]) -> Vec<(&str, usize, String)> {
+        found
+            .iter()
+            .map(|v| (v.axis.as_str(), v.index, v.value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn check_log_axes_reports_the_value_axis() {
+        let mut datasets = Vec::from([XYDataset::new()
+            .label("a")
+            .data(DatasetData(json!([0, 5, -2])))]);
+
+        let found = datasets.check_log_axes(&log_axes(&["x", "y"]), "", &LogAxisPolicy::Report);
+        assert_eq!(
+            violations(&found),
+            [("y", 0, "0".into()), ("y", 2, "-2".into())]
+        );
+
+        // on a horizontal chart bare values are plotted on the x axis, and the y axis holds the index
+        let found = datasets.check_log_axes(&log_axes(&["x"]), "y", &LogAxisPolicy::Report);
+        assert_eq!(
+            violations(&found),
+            [("x", 0, "0".into()), ("x", 2, "-2".into())]
+        );
+        assert!(datasets
+            .check_log_axes(&log_axes(&["y"]), "y", &LogAxisPolicy::Report)
+            .is_empty());
+        assert_eq!(datasets[0].data, DatasetData(json!([0, 5, -2])));
+    }
+
+    #[test]
+    fn check_log_axes_clamps_points_on_either_axis() {
+        let points = json!([{"x": 0, "y": 1}, {"x": 2, "y": -1}]);
+        let clamp = LogAxisPolicy::Clamp(NumberString::from(1));
+
+        for index_axis in ["x", "y"] {
+            let mut datasets = Vec::from([XYDataset::new().data(DatasetData(points.clone()))]);
+            let found = datasets.check_log_axes(&log_axes(&["x", "y"]), index_axis, &clamp);
+
+            assert_eq!(
+                violations(&found),
+                [("x", 0, "0".into()), ("y", 1, "-1".into())]
+            );
+            assert_eq!(
+                datasets[0].data,
+                DatasetData(json!([{"x": 1, "y": 1}, {"x": 2, "y": 1}]))
+            );
+        }
+    }
+
+    #[test]
+    fn check_log_axes_clamps_bare_values_on_horizontal_charts() {
+        let clamp = LogAxisPolicy::Clamp(NumberString::from(1));
+        let mut datasets = Vec::from([XYDataset::new().data(DatasetData(json!([0, 5])))]);
+
+        assert!(datasets
+            .check_log_axes(&log_axes(&["y"]), "y", &clamp)
+            .is_empty());
+        assert_eq!(datasets[0].data, DatasetData(json!([0, 5])));
+
+        datasets.check_log_axes(&log_axes(&["x"]), "y", &clamp);
+        assert_eq!(datasets[0].data, DatasetData(json!([1, 5])));
+    }
+
+    #[test]
+    fn check_log_axes_uses_the_chart_index_axis_for_single_point_datasets() {
+        let mut datasets = Vec::from([SinglePointDataset::new().data([0, 3])]);
+
+        let found = datasets.check_log_axes(&log_axes(&["x"]), "y", &LogAxisPolicy::Report);
+        assert_eq!(violations(&found), [("x", 0, "0".into())]);
+        assert!(datasets
+            .check_log_axes(&log_axes(&["y"]), "y", &LogAxisPolicy::Report)
+            .is_empty());
+
+        // the dataset's own indexAxis takes precedence
+        let mut datasets = Vec::from([SinglePointDataset::new().index_axis("x").data([0, 3])]);
+        let found = datasets.check_log_axes(
+            &log_axes(&["y"]),
+            "y",
+            &LogAxisPolicy::Clamp(NumberString::from(1)),
+        );
+        assert_eq!(violations(&found), [("y", 0, "0".into())]);
+        assert_eq!(
+            datasets[0].data,
+            [NumberString::from(1), NumberString::from(3)]
+        );
+    }
+
+    #[test]
+    fn scriptable_fields_fall_back_to_default() {
+        // stands in for a function set by `Chart::rationalise_js`, which can't be deserialized
+        let data_labels: DataLabels = serde_json::from_value(json!({
+            "color": {"function": true},
+            "font": [1],
+            "rotation": 45,
+        }))
+        .unwrap();
+        assert_eq!(
+            data_labels.rotation,
+            FnWithArgsOrT::T(NumberString::from(45))
+        );
+        assert!(data_labels.color.is_empty());
+        assert!(data_labels.font.is_none());
+
+        let ticks: ScaleTicks = serde_json::from_value(json!({"color": [], "count": 4})).unwrap();
+        assert!(ticks.color.is_empty());
+        assert_eq!(ticks.count, NumberString::from(4));
+    }
+
+    #[test]
+    fn per_slice_options_round_trip() {
+        let per_slice = SinglePointDataset::new()
+            .data([1, 2, 3])
+            .border_align(["inner", "center", "inner"])
+            .offset(vec![0, 10, 0])
+            .spacing([2, 0, 0])
+            .hover_offset(4)
+            .weight(2);
+        let json = serde_json::to_value(&per_slice).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "borderAlign": ["inner", "center", "inner"],
+                "data": [1, 2, 3],
+                "hoverOffset": 4,
+                "offset": [0, 10, 0],
+                "spacing": [2, 0, 0],
+                "weight": 2,
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SinglePointDataset>(json).unwrap(),
+            per_slice
+        );
+
+        let every_slice = SinglePointDataset::new().border_align("inner").offset(8);
+        let json = serde_json::to_value(&every_slice).unwrap();
+        assert_eq!(json, json!({"borderAlign": "inner", "offset": 8}));
+        assert_eq!(
+            serde_json::from_value::<SinglePointDataset>(json).unwrap(),
+            every_slice
+        );
+    }
+
+    #[test]
+    fn fill_round_trips() {
+        let fills = [
+            (Fill::Disabled, json!(false)),
+            (Fill::Origin, json!("origin")),
+            (Fill::Start, json!("start")),
+            (Fill::End, json!("end")),
+            (Fill::Stack, json!("stack")),
+            (Fill::Shape, json!("shape")),
+            (Fill::Dataset(2), json!(2)),
+            (Fill::Relative(-1), json!("-1")),
+            (Fill::Relative(2), json!("+2")),
+            (Fill::Value(NumberString::from(25)), json!({"value": 25})),
+            (
+                Fill::Target(
+                    FillTarget::new()
+                        .target(Fill::Relative(1))
+                        .above("red")
+                        .below("blue"),
+                ),
+                json!({"above": "red", "below": "blue", "target": "+1"}),
+            ),
+        ];
+
+        for (fill, expected) in fills {
+            let json = serde_json::to_value(&fill).unwrap();
+            assert_eq!(json, expected);
+            assert_eq!(serde_json::from_value::<Fill>(json).unwrap(), fill);
+        }
+        assert_eq!(
+            serde_json::from_value::<Fill>(json!(true)).unwrap(),
+            Fill::Origin
+        );
+        assert_eq!("2.5".parse(), Ok(Fill::Value("2.5".into())));
+    }
+
+    #[test]
+    fn fill_rejects_invalid_strings() {
+        for invalid in ["orign", "+x", "-", "1e", "NaN"] {
+            assert!(invalid.parse::<Fill>().is_err(), "{invalid}");
+            assert!(serde_json::from_value::<Fill>(json!(invalid)).is_err());
+        }
+        assert!(serde_json::from_value::<Fill>(json!({"value": "orign"})).is_err());
+        assert!(serde_json::from_value::<Fill>(json!({"above": "red"})).is_err());
+    }
+
+    #[test]
+    fn stepped_round_trips() {
+        for (stepped, expected) in [
+            (Stepped::Disabled, json!(false)),
+            (Stepped::Before, json!("before")),
+            (Stepped::After, json!("after")),
+            (Stepped::Middle, json!("middle")),
+        ] {
+            let json = serde_json::to_value(&stepped).unwrap();
+            assert_eq!(json, expected);
+            assert_eq!(serde_json::from_value::<Stepped>(json).unwrap(), stepped);
+        }
+        assert_eq!(
+            serde_json::from_value::<Stepped>(json!(true)).unwrap(),
+            Stepped::Before
+        );
+        assert!("midle".parse::<Stepped>().is_err());
+        assert!(serde_json::from_value::<Stepped>(json!("midle")).is_err());
+    }
+
+    #[test]
+    fn span_gaps_from_numbers_and_bools() {
+        assert_eq!(SpanGaps::from(true), SpanGaps::Bool(true));
+        assert_eq!(SpanGaps::from(false), SpanGaps::Bool(false));
+        assert_eq!(SpanGaps::from(500u64), SpanGaps::MaxGap(500.into()));
+        assert_eq!(SpanGaps::from(-1), SpanGaps::MaxGap((-1).into()));
+        assert_eq!(
+            SpanGaps::max_gap(std::time::Duration::from_secs(60)),
+            SpanGaps::MaxGap(60_000.into())
+        );
+    }
+}
@@ -30,7 +30,129 @@ impl DatasetData {
     pub fn from_minmax_array(iter: impl Iterator<Item = [NumberOrDateString; 2]>) -> Self {
         DatasetData(serde_json::to_value(iter.collect::<Vec<_>>()).unwrap())
     }
+
+    /// Uses any serializable records as the data, as is, pair this with the dataset's
+    /// `parsing` keys to tell Chart.js which fields to plot.
+    /// Returns the error of the first record that can't be serialized to JSON.
+    ///
+    /// This saves mapping records to points, it doesn't save memory: [`DatasetData`] holds JSON
+    /// until the chart is rendered, so each record is still copied into a `serde_json::Value`.
+    ///
+    /// ```rust no_run
+    /// #[derive(Serialize)]
+    /// struct Request {
+    ///     timestamp: String,
+    ///     latency_ms: f64,
+    ///     endpoint: String,
+    /// }
+    ///
+    /// XYDataset::new()
+    ///     .data(DatasetData::from_records(&requests)?)
+    ///     .parsing(ParsingKeys::new().x_axis_key("timestamp").y_axis_key("latency_ms"))
+    /// ```
+    pub fn from_records<T: Serialize>(
+        records: impl IntoIterator<Item = T>,
+    ) -> Result<Self, serde_json::Error> {
+        records
+            .into_iter()
+            .map(serde_json::to_value)
+            .collect::<Result<_, _>>()
+            .map(|records| DatasetData(serde_json::Value::Array(records)))
+    }
+
+    /// Finds the zero or negative values plotted against a logarithmic axis, `key` is the
+    /// axis of `{x, y}` points, bare values and `[min, max]` arrays are only checked on the value axis
+    pub(crate) fn check_log_axis(
+        &mut self,
+        key: &str,
+        is_value_axis: bool,
+        policy: &LogAxisPolicy,
+    ) -> Vec<(usize, NumberString)> {
+        let Some(points) = self.0.as_array_mut() else {
+            return Vec::new();
+        };
+
+        points
+            .iter_mut()
+            .enumerate()
+            .flat_map(|(idx, point)| {
+                let values = match point {
+                    serde_json::Value::Object(obj) => obj.get_mut(key).into_iter().collect(),
+                    serde_json::Value::Array(arr) if is_value_axis => arr.iter_mut().collect(),
+                    serde_json::Value::Array(_) => Vec::new(),
+                    value if is_value_axis => Vec::from([value]),
+                    _ => Vec::new(),
+                };
+
+                values
+                    .into_iter()
+                    .filter_map(|value| {
+                        let number = match &*value {
+                            serde_json::Value::Number(n) => n.as_f64(),
+                            serde_json::Value::String(s) => s.parse::<f64>().ok(),
+                            _ => None,
+                        }?;
+                        if number > 0.0 {
+                            return None;
+                        }
+
+                        let flagged = NumberString::from(number);
+                        if let LogAxisPolicy::Clamp(min) = policy {
+                            *value = serde_json::to_value(min).unwrap();
+                        }
+                        Some((idx, flagged))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+/// How [`crate::ChartExt::check_log_axes`] handles values that cannot be plotted on a logarithmic axis
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogAxisPolicy {
+    /// Leave the data untouched, and log a console warning for each zero or negative value
+    Report,
+    /// Replace each zero or negative value with the given positive value
+    Clamp(NumberString),
 }
+
+/// A zero or negative value found in a dataset bound to a logarithmic axis
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogAxisViolation {
+    /// Index of the dataset in `data.datasets`
+    pub dataset: usize,
+    /// The dataset's label
+    pub label: String,
+    /// The id of the logarithmic scale the value is plotted against
+    pub axis: String,
+    /// Index of the value in the dataset's `data`
+    pub index: usize,
+    /// The original value, before any clamping
+    pub value: NumberString,
+}
+
+/// Standard base64, used for `data:` urls
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    const CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (idx, b)| n | (*b as u32) << (16 - idx * 8));
+        for idx in 0..4 {
+            if idx <= chunk.len() {
+                encoded.push(CHARS[(n >> (18 - idx * 6)) as usize & 63] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
 impl PartialOrd for DatasetData {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
@@ -151,14 +273,28 @@ impl Display for Any {
         }
     }
 }
+/// A number, date or category label, serialized as a number when it parses as one.
+///
+/// **Dates:** `.into()` on a `chrono` or `time` value goes through the blanket `From<T: Display>`,
+/// which keeps that type's own `Display` format, eg. `2024-01-31 13:45:00 +00:00:00` for a `time::OffsetDateTime`
+/// or `2024-01-31 13:45:00 UTC` for a `chrono::DateTime<Utc>`.
+/// Chart.js and the ordering below can't read those formats, they sort as text after every date,
+/// so convert dates with [`ChartDateExt::to_chart_date`](crate::ChartDateExt::to_chart_date) instead.
+///
+/// Values are ordered numbers first, then dates (with the `chrono` or `time` features), then any other text,
+/// each compared by value, with the raw string breaking ties. The value is parsed once, when it is created.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
-pub struct NumberOrDateString(String);
+pub struct NumberOrDateString(String, SortKey);
 impl From<NumberString> for NumberOrDateString {
     fn from(value: NumberString) -> Self {
         value.0.into()
     }
 }
 impl NumberOrDateString {
+    fn new(s: String) -> Self {
+        let key = SortKey::parse(&s);
+        Self(s, key)
+    }
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -170,21 +306,35 @@ impl PartialOrd for NumberOrDateString {
 }
 impl Ord for NumberOrDateString {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        if let Some((s, o)) = self
-            .0
-            .parse::<rust_decimal::Decimal>()
-            .ok()
-            .zip(other.0.parse::<rust_decimal::Decimal>().ok())
-        {
-            s.cmp(&o)
-        } else {
-            self.0.cmp(&other.0)
+        self.1.cmp(&other.1).then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+/// The parsed value of a [`NumberOrDateString`], variants are ordered by category
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+enum SortKey {
+    Number(rust_decimal::Decimal),
+    // milliseconds since the Unix epoch, ISO 8601 dates of any precision are compared chronologically
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    Date(i128),
+    #[default]
+    Text,
+}
+impl SortKey {
+    fn parse(s: &str) -> Self {
+        if let Ok(number) = s.parse() {
+            return SortKey::Number(number);
         }
+        #[cfg(any(feature = "chrono", feature = "time"))]
+        if let Some(millis) = crate::dates::timestamp_millis(s) {
+            return SortKey::Date(millis);
+        }
+        SortKey::Text
     }
 }
 impl<T: Display> From<T> for NumberOrDateString {
     fn from(s: T) -> Self {
-        Self(s.to_string())
+        Self::new(s.to_string())
     }
 }
 #[allow(unknown_lints, clippy::to_string_trait_impl)]
@@ -212,7 +362,7 @@ impl<'de> Deserialize<'de> for NumberOrDateString {
     where
         D: serde::Deserializer<'de>,
     {
-        Any::deserialize(deserializer).map(|soi| Self(soi.to_string()))
+        Any::deserialize(deserializer).map(|soi| Self::new(soi.to_string()))
     }
 }
 
@@ -782,6 +932,194 @@ impl<const N: usize, T> From<FnWithArgs<N>> for FnWithArgsOrT<N, T> {
     }
 }
 
+/// Deserializes `T`, falling back to `T::default()` when the value has a different type,
+/// eg. a function which chartjs has set as the default for a scriptable option
+pub(crate) fn default_on_error<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: de::Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(T::deserialize(deserializer).unwrap_or_default())
+}
+
+/// A JSON value, leaving out any entries that can't be deserialized,
+/// eg. the functions [`Chart::rationalise_js`](crate::Chart::rationalise_js) sets for closures
+pub(crate) struct LossyValue(pub(crate) serde_json::Value);
+impl<'de> Deserialize<'de> for LossyValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct LossyVisitor;
+        impl<'de> de::Visitor<'de> for LossyVisitor {
+            type Value = LossyValue;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("any value")
+            }
+            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(LossyValue(v.into()))
+            }
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(LossyValue(v.into()))
+            }
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(LossyValue(v.into()))
+            }
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(LossyValue(v.into()))
+            }
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(LossyValue(v.into()))
+            }
+            fn visit_unit<E>(self) -> Result<Self::Value, E> {
+                Ok(LossyValue(serde_json::Value::Null))
+            }
+            fn visit_none<E>(self) -> Result<Self::Value, E> {
+                Ok(LossyValue(serde_json::Value::Null))
+            }
+            fn visit_some<D: de::Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+                LossyValue::deserialize(d)
+            }
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut items = Vec::new();
+                while let Some(item) = seq.next_element::<LossyValue>()? {
+                    items.push(item.0);
+                }
+                Ok(LossyValue(serde_json::Value::Array(items)))
+            }
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = serde_json::Map::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    if let Ok(value) = map.next_value::<LossyValue>() {
+                        entries.insert(key, value.0);
+                    }
+                }
+                Ok(LossyValue(serde_json::Value::Object(entries)))
+            }
+        }
+
+        deserializer.deserialize_any(LossyVisitor)
+    }
+}
+
+/// Title text, a single line or several lines
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TitleText(Vec<String>);
+impl TitleText {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn lines(&self) -> &[String] {
+        &self.0
+    }
+}
+impl From<&str> for TitleText {
+    fn from(value: &str) -> Self {
+        Self(Vec::from([value.to_string()]))
+    }
+}
+impl From<String> for TitleText {
+    fn from(value: String) -> Self {
+        Self(Vec::from([value]))
+    }
+}
+impl<S: Into<String>> From<Vec<S>> for TitleText {
+    fn from(value: Vec<S>) -> Self {
+        Self(value.into_iter().map(Into::into).collect())
+    }
+}
+impl<S: Into<String>, const N: usize> From<[S; N]> for TitleText {
+    fn from(value: [S; N]) -> Self {
+        Self(value.into_iter().map(Into::into).collect())
+    }
+}
+impl Serialize for TitleText {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.0.as_slice() {
+            [line] => serializer.serialize_str(line),
+            lines => lines.serialize(serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for TitleText {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(match OneOrMany::<String>::deserialize(deserializer)? {
+            OneOrMany::One(s) => s.into(),
+            OneOrMany::Many(v) => Self(v),
+        })
+    }
+}
+
+/// One value for every element, or a value per element, eg. per pie slice
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+impl From<&str> for OneOrMany<String> {
+    fn from(value: &str) -> Self {
+        Self::One(value.to_string())
+    }
+}
+impl From<String> for OneOrMany<String> {
+    fn from(value: String) -> Self {
+        Self::One(value)
+    }
+}
+impl<S: Into<String>> From<Vec<S>> for OneOrMany<String> {
+    fn from(value: Vec<S>) -> Self {
+        Self::Many(value.into_iter().map(Into::into).collect())
+    }
+}
+impl<S: Into<String>, const N: usize> From<[S; N]> for OneOrMany<String> {
+    fn from(value: [S; N]) -> Self {
+        Self::Many(value.into_iter().map(Into::into).collect())
+    }
+}
+impl<T: Into<NumberString>> From<Vec<T>> for OneOrMany<NumberString> {
+    fn from(value: Vec<T>) -> Self {
+        Self::Many(value.into_iter().map(Into::into).collect())
+    }
+}
+impl<T: Into<NumberString>, const N: usize> From<[T; N]> for OneOrMany<NumberString> {
+    fn from(value: [T; N]) -> Self {
+        Self::Many(value.into_iter().map(Into::into).collect())
+    }
+}
+// a blanket `T: Into<NumberString>` impl would overlap the `Vec` impl, so each number type gets its own
+macro_rules! one_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for OneOrMany<NumberString> {
+            fn from(value: $t) -> Self {
+                Self::One(value.into())
+            }
+        })*
+    };
+}
+one_number!(
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64,
+    NumberString
+);
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct NumberString(String);
 impl From<NumberOrDateString> for NumberString {
@@ -889,3 +1227,109 @@ impl<T: Serialize + ChartJsRsObject, U: Serialize + DeserializeOwned> From<T>
         serde_json::from_value(serde_json::to_value(value).unwrap()).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_encode_rfc_4648_vectors() {
+        for (input, expected) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(base64_encode(input.as_bytes()), expected);
+        }
+    }
+
+    fn sorted(labels: &[&str]) -> Vec<String> {
+        let mut labels = labels
+            .iter()
+            .map(NumberOrDateString::from)
+            .collect::<Vec<_>>();
+        labels.sort();
+        labels.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn number_or_date_string_orders_numbers_then_text() {
+        assert_eq!(
+            sorted(&["b", "10", "a", "9", "-1.5", "10.0"]),
+            ["-1.5", "9", "10", "10.0", "a", "b"]
+        );
+    }
+
+    #[test]
+    fn number_or_date_string_ordering_is_transitive() {
+        let labels = ["10", "9", "a", "2024-01-01", "2023-12-31T23:00:00Z", ""]
+            .map(NumberOrDateString::from);
+        for a in &labels {
+            for b in &labels {
+                for c in &labels {
+                    if a <= b && b <= c {
+                        assert!(a <= c, "{a:?} <= {b:?} <= {c:?}");
+                    }
+                }
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+            }
+        }
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn number_or_date_string_orders_dates_chronologically() {
+        assert_eq!(
+            sorted(&[
+                "z",
+                "2024-01-01",
+                "2023-12-31T23:00:00Z",
+                "2023-12-31T23:00",
+                "5"
+            ]),
+            [
+                "5",
+                "2023-12-31T23:00",
+                "2023-12-31T23:00:00Z",
+                "2024-01-01",
+                "z"
+            ]
+        );
+    }
+
+    #[test]
+    fn dataset_data_from_records() {
+        #[derive(Serialize)]
+        struct Request {
+            timestamp: &'static str,
+            latency_ms: u32,
+        }
+
+        let data = DatasetData::from_records([
+            Request {
+                timestamp: "2024-01-01T00:00:00Z",
+                latency_ms: 12,
+            },
+            Request {
+                timestamp: "2024-01-01T00:01:00Z",
+                latency_ms: 30,
+            },
+        ])
+        .unwrap();
+        assert_eq!(
+            data.0,
+            serde_json::json!([
+                {"timestamp": "2024-01-01T00:00:00Z", "latency_ms": 12},
+                {"timestamp": "2024-01-01T00:01:00Z", "latency_ms": 30},
+            ])
+        );
+
+        // JSON object keys must be strings
+        let unserializable = std::collections::HashMap::from([((1, 2), 3)]);
+        assert!(DatasetData::from_records([unserializable]).is_err());
+    }
+}
@@ -36,8 +36,15 @@ impl ChartExt for Scatter {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+impl Scatter {
+    /// Stacks the datasets, stacking the value scales they are bound to
+    pub fn stacked(mut self, stacking: crate::stacking::Stacking) -> Self {
+        stacking.apply(self.data.get_datasets(), &mut self.options, false);
+        self
+    }
+}
 
+#[derive(Debug, Default, Clone)]
 pub struct ScatterString;
 impl<'de> Deserialize<'de> for ScatterString {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::objects::*;
+
+/// Stacks the datasets of a [`crate::bar::Bar`] or [`crate::scatter::Scatter`] chart,
+/// see `Bar::stacked` and `Scatter::stacked`
+///
+/// ```rust no_run
+/// Bar::<Vec<XYDataset>>::new("capacity")
+///     .data(Dataset::new().datasets([used, reserved, free]))
+///     .stacked(
+///         Stacking::new()
+///             .group("cluster-a", ["Used", "Reserved", "Free"])
+///             .percentage(true),
+///     )
+///     .into_chart()
+///     .render();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stacking {
+    groups: Vec<(String, Vec<String>)>,
+    percentage: bool,
+}
+impl Stacking {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stacks the datasets with the given labels together, under the `stack` group `id`.
+    /// Datasets which aren't in any group keep their `stack`, Chart.js stacks all datasets without one together.
+    pub fn group<T: Into<String>>(
+        mut self,
+        id: impl Into<String>,
+        labels: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.groups
+            .push((id.into(), labels.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// Normalises each x bucket of every stack to 100%, the raw values are kept for the tooltips
+    pub fn percentage(mut self, percentage: bool) -> Self {
+        self.percentage = percentage;
+        self
+    }
+
+    /// `stack_index_axis` is needed for bar charts, where the bars in a stack share an index axis
+    pub(crate) fn apply(
+        &self,
+        datasets: &mut [XYDataset],
+        options: &mut ChartOptions,
+        stack_index_axis: bool,
+    ) {
+        let horizontal = options.indexAxis == "y";
+        let (index_key, value_key) = if horizontal { ("y", "x") } else { ("x", "y") };
+
+        for dataset in datasets.iter_mut() {
+            if let Some((id, _)) = self
+                .groups
+                .iter()
+                .find(|(_, labels)| labels.contains(&dataset.label))
+            {
+                dataset.stack = id.clone();
+            }
+        }
+
+        let totals = bucket_totals(datasets, index_key, value_key);
+
+        for dataset in datasets.iter_mut() {
+            for (idx, point) in dataset
+                .data
+                .0
+                .as_array_mut()
+                .into_iter()
+                .flatten()
+                .enumerate()
+            {
+                let Some((bucket, value)) = bucket_value(idx, point, index_key, value_key) else {
+                    continue;
+                };
+                let total = totals[&(dataset.stack.clone(), bucket)];
+                let percentage = if total == 0.0 {
+                    0.0
+                } else {
+                    value / total * 100.0
+                };
+
+                // bare values become points, a category scale reads a numeric index as the label at that index
+                if !point.is_object() {
+                    *point =
+                        Value::Object([(index_key.to_string(), idx.into())].into_iter().collect());
+                }
+                let point = point.as_object_mut().unwrap();
+                point.insert("value".into(), number(value));
+                point.insert("percentage".into(), number(percentage));
+                if self.percentage {
+                    point.insert(value_key.into(), number(percentage));
+                }
+            }
+        }
+
+        let scales = options.scales.get_or_insert_with(HashMap::new);
+        for dataset in datasets.iter() {
+            let x_axis = if dataset.xAxisID.is_empty() {
+                "x"
+            } else {
+                &dataset.xAxisID
+            };
+            let y_axis = if dataset.yAxisID.is_empty() {
+                "y"
+            } else {
+                &dataset.yAxisID
+            };
+            let (index_axis, value_axis) = if horizontal {
+                (y_axis, x_axis)
+            } else {
+                (x_axis, y_axis)
+            };
+
+            let value_scale = scales.entry(value_axis.to_string()).or_default();
+            value_scale.stacked = Some(true);
+            if self.percentage {
+                value_scale.max = 100.into();
+            }
+            if stack_index_axis {
+                scales.entry(index_axis.to_string()).or_default().stacked = Some(true);
+            }
+        }
+
+        let callbacks = options
+            .plugins
+            .get_or_insert_with(Default::default)
+            .tooltip
+            .get_or_insert_with(Default::default)
+            .callbacks
+            .get_or_insert_with(Default::default);
+        if callbacks.label.is_empty() {
+            callbacks.label = FnWithArgs::<1>::new()
+                .args(["context"])
+                .js_body(
+                    "const point = context.raw || {};
+const value = point.value ?? context.formattedValue;
+const percentage = typeof point.percentage === 'number' ? ` (${point.percentage.toFixed(1)}%)` : '';",
+                )
+                .js_return_value("`${context.dataset.label}: ${value}${percentage}`");
+        }
+    }
+}
+
+/// Sums the absolute values of each index bucket, per stack
+fn bucket_totals(
+    datasets: &[XYDataset],
+    index_key: &str,
+    value_key: &str,
+) -> HashMap<(String, String), f64> {
+    let mut totals = HashMap::new();
+    for dataset in datasets {
+        for (idx, point) in dataset.data.0.as_array().into_iter().flatten().enumerate() {
+            if let Some((bucket, value)) = bucket_value(idx, point, index_key, value_key) {
+                *totals.entry((dataset.stack.clone(), bucket)).or_default() += value.abs();
+            }
+        }
+    }
+    totals
+}
+
+/// The bucket and value of `{x, y}` points, bare values are bucketed by their index in the data
+fn bucket_value(
+    idx: usize,
+    point: &Value,
+    index_key: &str,
+    value_key: &str,
+) -> Option<(String, f64)> {
+    let as_f64 = |value: &Value| match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    };
+
+    match point {
+        Value::Object(point) => Some((
+            point.get(index_key)?.to_string(),
+            as_f64(point.get(value_key)?)?,
+        )),
+        value => Some((idx.to_string(), as_f64(value)?)),
+    }
+}
+
+fn number(n: f64) -> Value {
+    serde_json::to_value(NumberString::from(n)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn dataset(label: &str, data: Value) -> XYDataset {
+        XYDataset::new().label(label).data(DatasetData(data))
+    }
+
+    #[test]
+    fn bucket_totals_per_stack() {
+        let datasets = [
+            dataset("a", json!([{"x": "Jan", "y": 10}, {"x": "Feb", "y": -5}])),
+            dataset("b", json!([{"x": "Jan", "y": "30"}, {"x": "Feb", "y": 15}])),
+            dataset("c", json!([4, 6])).stack("other"),
+        ];
+        let totals = bucket_totals(&datasets, "x", "y");
+
+        assert_eq!(totals[&(String::new(), "\"Jan\"".into())], 40.0);
+        assert_eq!(totals[&(String::new(), "\"Feb\"".into())], 20.0);
+        assert_eq!(totals[&("other".into(), "0".into())], 4.0);
+        assert_eq!(totals[&("other".into(), "1".into())], 6.0);
+        assert_eq!(totals.len(), 4);
+    }
+
+    #[test]
+    fn percentage_normalises_bare_values() {
+        let mut datasets = [dataset("a", json!([10, 30])), dataset("b", json!([30, 10]))];
+        let mut options = ChartOptions::new();
+        Stacking::new()
+            .group("s", ["a", "b"])
+            .percentage(true)
+            .apply(&mut datasets, &mut options, true);
+
+        assert_eq!(
+            datasets[0].data.0,
+            json!([
+                {"x": 0, "y": 25, "value": 10, "percentage": 25},
+                {"x": 1, "y": 75, "value": 30, "percentage": 75},
+            ])
+        );
+        assert_eq!(datasets[1].stack, "s");
+        let scales = options.scales.unwrap();
+        assert_eq!(scales["y"].stacked, Some(true));
+        assert_eq!(scales["y"].max, 100.into());
+        assert_eq!(scales["x"].stacked, Some(true));
+    }
+
+    #[test]
+    fn percentage_keeps_points_and_index_axis() {
+        let mut datasets = [
+            dataset("a", json!([{"x": 1, "y": "Jan"}])),
+            dataset("b", json!([{"x": 3, "y": "Jan"}])),
+        ];
+        let mut options = ChartOptions::new().index_axis("y");
+        Stacking::new()
+            .percentage(true)
+            .apply(&mut datasets, &mut options, false);
+
+        assert_eq!(
+            datasets[1].data.0,
+            json!([{"x": 75, "y": "Jan", "value": 3, "percentage": 75}])
+        );
+        let scales = options.scales.unwrap();
+        assert_eq!(scales["x"].stacked, Some(true));
+        assert!(!scales.contains_key("y"));
+    }
+}
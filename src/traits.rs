@@ -14,6 +14,17 @@ impl<T: Display> ChartJsRsObject for T {
 
 pub trait DatasetTrait: for<'a> Deserialize<'a> + Serialize + Default + Clone {
     fn labels(self) -> Vec<NumberOrDateString>;
+
+    /// Finds (and depending on `policy`, clamps) the zero or negative values in datasets bound to one of `log_axes`,
+    /// `index_axis` is the chart's `indexAxis`, `x` unless the chart is horizontal
+    fn check_log_axes(
+        &mut self,
+        _log_axes: &HashSet<String>,
+        _index_axis: &str,
+        _policy: &LogAxisPolicy,
+    ) -> Vec<LogAxisViolation> {
+        Vec::new()
+    }
 }
 pub trait DatasetDataExt {
     fn presorted_to_dataset_data(self) -> DatasetData;
@@ -2,7 +2,12 @@ use js_sys::{Array, Object, Reflect};
 use std::cell::RefCell;
 use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
 
-use crate::{exports::*, BoolString, FnWithArgs, FnWithArgsOrT, NumberString};
+use gloo_utils::format::JsValueSerdeExt;
+
+use crate::{
+    exports::*, BoolString, FnWithArgs, FnWithArgsOrT, LabelContent, NumberString, Transition,
+    UpdateMode, RENDERED_IMAGE_CONTENT,
+};
 
 pub fn get_order_fn(
     lhs: &crate::NumberOrDateString,
@@ -31,6 +36,62 @@ thread_local! {
     });
 }
 
+/// Adds a named entry to Chart.js' `Tooltip.positioners`, use it with
+/// `TooltipPlugin::position(TooltipPosition::Custom(name))`.
+///
+/// `f` takes the active elements and the event position, and returns where the tooltip's caret goes,
+/// or `None` to hide the tooltip. It must be registered before the chart is rendered.
+///
+/// ```rust no_run
+/// register_tooltip_positioner("followCursor", |_elements, (x, y)| Some((x, y)));
+/// register_tooltip_positioner("pinToTop", |elements, _| {
+///     elements.first().map(|element| (element.x, 0.0))
+/// });
+/// ```
+pub fn register_tooltip_positioner<
+    F: Fn(Vec<crate::coordinate::PositionerElement>, (f64, f64)) -> Option<(f64, f64)> + 'static,
+>(
+    name: &str,
+    f: F,
+) {
+    let closure = wasm_bindgen::closure::Closure::wrap(Box::new(
+        move |elements: JsValue, position: JsValue| -> JsValue {
+            let elements = match crate::coordinate::PositionerElement::parse_array(&elements) {
+                Ok(elements) => elements,
+                Err(e) => {
+                    gloo_console::error!(format!("Error parsing tooltip elements: {e}"));
+                    return JsValue::FALSE;
+                }
+            };
+            let get = |key: &str| {
+                Reflect::get(&position, &key.into())
+                    .ok()
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or_default()
+            };
+
+            match f(elements, (get("x"), get("y"))) {
+                Some((x, y)) => {
+                    let out = Object::new();
+                    let _ = Reflect::set(&out, &"x".into(), &x.into());
+                    let _ = Reflect::set(&out, &"y".into(), &y.into());
+                    out.into()
+                }
+                None => JsValue::FALSE,
+            }
+        },
+    )
+        as Box<dyn Fn(JsValue, JsValue) -> JsValue>);
+
+    match get_path(&gloo_utils::window(), "Chart.Tooltip.positioners") {
+        Some(positioners) if positioners.is_object() => {
+            let _ = Reflect::set(&positioners, &name.into(), closure.as_ref());
+            closure.forget();
+        }
+        _ => gloo_console::error!("Chart.Tooltip.positioners not found, is Chart.js loaded?"),
+    }
+}
+
 pub fn uncircle_chartjs_value_to_serde_json_value(
     js: impl AsRef<JsValue>,
 ) -> Result<serde_json::Value, String> {
@@ -89,6 +150,103 @@ fn object_values_at(j: &JsValue, item: &str) -> Option<JsValue> {
     })
 }
 
+/// The id of the canvas that `chart` is rendered in
+pub(crate) fn canvas_id(chart: &JsValue) -> Option<String> {
+    get_path(chart, "canvas.id").and_then(|id| id.as_string())
+}
+
+/// Creates or moves the `<canvas id>-tooltip` element next to the canvas, with its caret at `caret`
+pub(crate) fn show_html_tooltip(chart_id: &str, (caret_x, caret_y): (f64, f64), html: &str) {
+    let document = gloo_utils::document();
+    let Some(canvas) = document.get_element_by_id(chart_id) else {
+        return;
+    };
+    let tooltip_id = format!("{chart_id}-tooltip");
+
+    let tooltip = match document.get_element_by_id(&tooltip_id) {
+        Some(tooltip) => tooltip,
+        None => {
+            let Ok(tooltip) = document.create_element("div") else {
+                return;
+            };
+            tooltip.set_id(&tooltip_id);
+            let _ = tooltip.set_attribute("style", "pointer-events: none;");
+            if let Some(parent) = canvas.parent_element() {
+                let _ = parent.append_child(&tooltip);
+            }
+            tooltip
+        }
+    };
+
+    let offset = |key: &str| {
+        Reflect::get(&canvas, &key.into())
+            .ok()
+            .and_then(|v| v.as_f64())
+            .unwrap_or_default()
+    };
+    // only touch the properties positioning needs, so any other user styling is kept
+    let style = Reflect::get(&tooltip, &"style".into()).unwrap_or_default();
+    if let Ok(set_property) =
+        Reflect::get(&style, &"setProperty".into()).and_then(|f| f.dyn_into::<js_sys::Function>())
+    {
+        for (property, value) in [
+            ("position", "absolute".to_string()),
+            ("opacity", "1".to_string()),
+            ("left", format!("{}px", offset("offsetLeft") + caret_x)),
+            ("top", format!("{}px", offset("offsetTop") + caret_y)),
+        ] {
+            let _ = set_property.call2(&style, &property.into(), &value.into());
+        }
+    }
+    tooltip.set_inner_html(html);
+}
+
+/// Removes the `<canvas id>-tooltip` element, if there is one
+pub(crate) fn remove_html_tooltip(chart_id: &str) {
+    if let Some(tooltip) = gloo_utils::document().get_element_by_id(&format!("{chart_id}-tooltip"))
+    {
+        tooltip.remove();
+    }
+}
+
+/// Replaces a label's `{ src, width, height }` image content with a loaded `Image`.
+/// The `Image` keeps that object in an enumerable field, so the chart can be read back and updated.
+fn load_label_image(label: &JsValue) {
+    let Some(content) = object_values_at(label, "content") else {
+        return;
+    };
+    if Array::is_array(&content) || !content.is_object() {
+        return;
+    }
+    let Some(src) = object_values_at(&content, "src") else {
+        return;
+    };
+    // `Image` only exists on the main thread, and chartjs-plugin-annotation only draws `HTMLImageElement`s
+    let Some(image_class) = object_values_at(&js_sys::global(), "Image")
+        .and_then(|class| class.dyn_into::<js_sys::Function>().ok())
+    else {
+        return;
+    };
+    let Ok(image) = Reflect::construct(&image_class, &Array::new()) else {
+        return;
+    };
+
+    for size in ["width", "height"] {
+        if let Some(value) = object_values_at(&content, size) {
+            let _ = Reflect::set(&image, &size.into(), &value);
+        }
+    }
+    let _ = Reflect::set(&image, &"src".into(), &src);
+    // the image's own fields are getters, so keep the content as an enumerable field for `get_chart_from_id`
+    let _ = Reflect::set(&image, &RENDERED_IMAGE_CONTENT.into(), &content);
+    let _ = Reflect::set(label, &"content".into(), &image);
+}
+
+/// Serializes `transition` for `update_chart`, which applies it to that one update
+pub(crate) fn transition_to_js(transition: &Transition) -> JsValue {
+    <JsValue as JsValueSerdeExt>::from_serde(transition).unwrap_or_default()
+}
+
 impl Chart {
     // pub fn new(chart: JsValue, id: String) -> Option<Self> {
     //     chart.is_object().then_some(Self{
@@ -127,13 +285,87 @@ impl Chart {
 
     /// This should not be used on a chart with a worker attached.
     /// If it is, it will always return `false`
-    pub fn update(self, animate: bool) -> bool {
-        update_chart(self.obj, &self.id, animate)
+    ///
+    /// `true` and `false` still work, as the default update and `UpdateMode::None`
+    pub fn update(self, mode: impl Into<UpdateMode>) -> bool {
+        update_chart(
+            self.obj,
+            &self.id,
+            mode.into().as_str().map(Into::into),
+            JsValue::UNDEFINED,
+        )
+    }
+
+    /// Resets the zoom and pan of the rendered chart with this id, requires chartjs-plugin-zoom.
+    /// Returns `false` if there is no such chart. Charts rendered in a worker use `WorkerChart::reset_zoom_async`.
+    pub fn reset_zoom(&self) -> bool {
+        reset_zoom(&self.id)
+    }
+
+    /// Zooms a scale of the rendered chart with this id to `min..max`, requires chartjs-plugin-zoom.
+    /// Returns `false` if there is no such chart. Charts rendered in a worker use `WorkerChart::zoom_scale_async`.
+    pub fn zoom_scale(
+        &self,
+        scale_id: &str,
+        min: impl Into<crate::NumberOrDateString>,
+        max: impl Into<crate::NumberOrDateString>,
+        mode: impl Into<UpdateMode>,
+    ) -> bool {
+        let to_js = |value: crate::NumberOrDateString| {
+            <JsValue as JsValueSerdeExt>::from_serde(&value).unwrap_or_default()
+        };
+        zoom_scale(
+            &self.id,
+            scale_id,
+            to_js(min.into()),
+            to_js(max.into()),
+            mode.into().as_str().map(Into::into),
+        )
+    }
+
+    /// Updates the chart, animated with `transition`.
+    /// The transition only applies to this update, the chart's own animation options are restored afterwards.
+    ///
+    /// ```rust no_run
+    /// // data refreshes fade in slowly, user interactions keep the default animation
+    /// chart.into_chart().update_with_transition(
+    ///     UpdateMode::Custom("refresh".into()),
+    ///     Transition::new().animation(Animation::new().duration(2000).easing(Easing::EaseInOutSine)),
+    /// );
+    /// ```
+    pub fn update_with_transition(
+        self,
+        mode: impl Into<UpdateMode>,
+        transition: Transition,
+    ) -> bool {
+        self.rationalise_js();
+        update_chart(
+            self.obj,
+            &self.id,
+            mode.into().as_str().map(Into::into),
+            transition_to_js(&transition),
+        )
     }
 
     /// Converts serialized FnWithArgs to JS Function's
     /// For new chart options, this will need to be updated
     pub fn rationalise_js(&self) {
+        let rationalise_datalabels = |datalabels: &JsValue| {
+            FnWithArgsOrT::<1, String>::rationalise_1_level(datalabels, "align");
+            FnWithArgsOrT::<1, String>::rationalise_1_level(datalabels, "anchor");
+            FnWithArgsOrT::<1, String>::rationalise_1_level(datalabels, "backgroundColor");
+            FnWithArgsOrT::<1, String>::rationalise_1_level(datalabels, "color");
+            FnWithArgsOrT::<1, BoolString>::rationalise_1_level(datalabels, "display");
+            // Font objects deserialize as `FnWithArgsOrT::T`, so only look for functions
+            FnWithArgs::<1>::rationalise_1_level(datalabels, "font");
+            FnWithArgs::<2>::rationalise_1_level(datalabels, "formatter");
+            FnWithArgsOrT::<1, NumberString>::rationalise_1_level(datalabels, "offset");
+            FnWithArgsOrT::<1, NumberString>::rationalise_1_level(datalabels, "rotation");
+            FnWithArgs::<2>::rationalise_2_levels(datalabels, ("listeners", "click"));
+            FnWithArgs::<2>::rationalise_2_levels(datalabels, ("listeners", "enter"));
+            FnWithArgs::<2>::rationalise_2_levels(datalabels, ("listeners", "leave"));
+        };
+
         // Handle data.datasets
         Array::from(&get_path(&self.obj, "data.datasets").unwrap())
             .iter()
@@ -141,24 +373,14 @@ impl Chart {
                 FnWithArgsOrT::<2, String>::rationalise_1_level(&dataset, "backgroundColor");
                 FnWithArgs::<1>::rationalise_2_levels(&dataset, ("segment", "borderDash"));
                 FnWithArgs::<1>::rationalise_2_levels(&dataset, ("segment", "borderColor"));
-                FnWithArgsOrT::<1, String>::rationalise_2_levels(&dataset, ("datalabels", "align"));
-                FnWithArgsOrT::<1, String>::rationalise_2_levels(
-                    &dataset,
-                    ("datalabels", "anchor"),
-                );
-                FnWithArgsOrT::<1, String>::rationalise_2_levels(
-                    &dataset,
-                    ("datalabels", "backgroundColor"),
-                );
-                FnWithArgs::<2>::rationalise_2_levels(&dataset, ("datalabels", "formatter"));
-                FnWithArgsOrT::<1, NumberString>::rationalise_2_levels(
-                    &dataset,
-                    ("datalabels", "offset"),
-                );
-                FnWithArgsOrT::<1, BoolString>::rationalise_2_levels(
-                    &dataset,
-                    ("datalabels", "display"),
-                );
+                if let Some(datalabels) = object_values_at(&dataset, "datalabels") {
+                    rationalise_datalabels(&datalabels);
+                    if let Some(labels) = object_values_at(&datalabels, "labels") {
+                        Object::values(&labels.dyn_into().unwrap())
+                            .iter()
+                            .for_each(|label| rationalise_datalabels(&label));
+                    }
+                }
             });
 
         // Handle options.scales
@@ -167,18 +389,132 @@ impl Chart {
                 .iter()
                 .for_each(|scale| {
                     FnWithArgs::<3>::rationalise_2_levels(&scale, ("ticks", "callback"));
+                    FnWithArgsOrT::<1, String>::rationalise_2_levels(
+                        &scale,
+                        ("ticks", "backdropColor"),
+                    );
+                    FnWithArgsOrT::<1, String>::rationalise_2_levels(&scale, ("ticks", "color"));
+                    // Font objects deserialize as `FnWithArgsOrT::T`, so only look for functions
+                    FnWithArgs::<1>::rationalise_2_levels(&scale, ("ticks", "font"));
+                    FnWithArgsOrT::<1, BoolString>::rationalise_2_levels(
+                        &scale,
+                        ("ticks", "showLabelBackdrop"),
+                    );
+                    FnWithArgsOrT::<1, String>::rationalise_2_levels(
+                        &scale,
+                        ("ticks", "textStrokeColor"),
+                    );
+                    FnWithArgsOrT::<1, NumberString>::rationalise_2_levels(
+                        &scale,
+                        ("ticks", "textStrokeWidth"),
+                    );
+                    FnWithArgsOrT::<1, String>::rationalise_2_levels(&scale, ("grid", "color"));
+                    FnWithArgsOrT::<1, NumberString>::rationalise_2_levels(
+                        &scale,
+                        ("grid", "lineWidth"),
+                    );
+                    FnWithArgsOrT::<1, String>::rationalise_2_levels(&scale, ("grid", "tickColor"));
+                    FnWithArgsOrT::<1, NumberString>::rationalise_2_levels(
+                        &scale,
+                        ("grid", "tickWidth"),
+                    );
+                });
+        }
+
+        // Handle the options callbacks, options.animation, options.animations and options.transitions
+        if let Some(options) = object_values_at(&self.obj, "options") {
+            FnWithArgsOrT::<1, NumberString>::rationalise_2_levels(
+                &options,
+                ("animation", "delay"),
+            );
+            FnWithArgs::<1>::rationalise_2_levels(&options, ("animation", "onComplete"));
+            FnWithArgs::<1>::rationalise_2_levels(&options, ("animation", "onProgress"));
+            FnWithArgs::<3>::rationalise_1_level(&options, "onClick");
+            FnWithArgs::<3>::rationalise_1_level(&options, "onHover");
+            FnWithArgs::<2>::rationalise_1_level(&options, "onResize");
+        }
+        let rationalise_animations = |animations: JsValue| {
+            Object::values(&animations.dyn_into().unwrap())
+                .iter()
+                .for_each(|animation| {
+                    FnWithArgsOrT::<1, NumberString>::rationalise_1_level(&animation, "delay");
+                });
+        };
+        if let Some(animations) = object_values_at(&self.obj, "options.animations") {
+            rationalise_animations(animations);
+        }
+        if let Some(transitions) = object_values_at(&self.obj, "options.transitions") {
+            Object::values(&transitions.dyn_into().unwrap())
+                .iter()
+                .for_each(|transition| {
+                    FnWithArgsOrT::<1, NumberString>::rationalise_2_levels(
+                        &transition,
+                        ("animation", "delay"),
+                    );
+                    FnWithArgs::<1>::rationalise_2_levels(&transition, ("animation", "onComplete"));
+                    FnWithArgs::<1>::rationalise_2_levels(&transition, ("animation", "onProgress"));
+                    if let Some(animations) = object_values_at(&transition, "animations") {
+                        rationalise_animations(animations);
+                    }
                 });
         }
 
+        // Handle options.plugins.annotation.annotations
+        if let Some(annotations) =
+            object_values_at(&self.obj, "options.plugins.annotation.annotations")
+        {
+            Object::values(&annotations.dyn_into().unwrap())
+                .iter()
+                .for_each(|annotation| {
+                    FnWithArgs::<2>::rationalise_1_level(&annotation, "click");
+                    FnWithArgsOrT::<1, LabelContent>::rationalise_1_level(&annotation, "content");
+                    FnWithArgsOrT::<1, LabelContent>::rationalise_2_levels(
+                        &annotation,
+                        ("label", "content"),
+                    );
+                    FnWithArgsOrT::<1, BoolString>::rationalise_1_level(&annotation, "display");
+                    FnWithArgs::<2>::rationalise_1_level(&annotation, "enter");
+                    FnWithArgs::<2>::rationalise_1_level(&annotation, "leave");
+                    load_label_image(&annotation);
+                    if let Some(label) = object_values_at(&annotation, "label") {
+                        load_label_image(&label);
+                    }
+                });
+        }
+
+        // Handle options.plugins.zoom
+        if let Some(zoom) = object_values_at(&self.obj, "options.plugins.zoom") {
+            FnWithArgs::<1>::rationalise_2_levels(&zoom, ("pan", "onPanComplete"));
+            FnWithArgs::<1>::rationalise_2_levels(&zoom, ("zoom", "onZoomComplete"));
+        }
+
         // Handle options.plugins.legend
         if let Some(legend) = object_values_at(&self.obj, "options.plugins.legend") {
             FnWithArgs::<2>::rationalise_2_levels(&legend, ("labels", "filter"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("labels", "generateLabels"));
             FnWithArgs::<3>::rationalise_2_levels(&legend, ("labels", "sort"));
+            FnWithArgs::<3>::rationalise_1_level(&legend, "onClick");
+            FnWithArgs::<3>::rationalise_1_level(&legend, "onHover");
+            FnWithArgs::<3>::rationalise_1_level(&legend, "onLeave");
         }
         // Handle options.plugins.tooltip
         if let Some(legend) = object_values_at(&self.obj, "options.plugins.tooltip") {
+            FnWithArgs::<1>::rationalise_1_level(&legend, "external");
             FnWithArgs::<1>::rationalise_1_level(&legend, "filter");
+            FnWithArgs::<3>::rationalise_1_level(&legend, "itemSort");
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "afterBody"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "afterFooter"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "afterLabel"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "afterTitle"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "beforeBody"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "beforeFooter"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "beforeLabel"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "beforeTitle"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "footer"));
             FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "label"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "labelColor"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "labelPointStyle"));
+            FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "labelTextColor"));
             FnWithArgs::<1>::rationalise_2_levels(&legend, ("callbacks", "title"));
         }
     }
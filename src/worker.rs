@@ -67,7 +67,17 @@ pub(crate) enum MessageContent {
     Update {
         updated: JsValue,
         id: String,
-        animate: bool,
+        mode: Option<String>,
+        transition: JsValue,
+    },
+
+    /// Resets the zoom without a `scale_id`
+    Zoom {
+        id: String,
+        scale_id: Option<String>,
+        min: JsValue,
+        max: JsValue,
+        mode: Option<String>,
     },
 
     Other,
@@ -105,11 +115,26 @@ impl From<MessageContent> for JsValue {
             MessageContent::Update {
                 updated,
                 id,
-                animate,
+                mode,
+                transition,
             } => obj! {
                 "updated" => updated,
                 "id" => id,
-                "animate" => animate,
+                "mode" => mode,
+                "transition" => transition,
+            },
+            MessageContent::Zoom {
+                id,
+                scale_id,
+                min,
+                max,
+                mode,
+            } => obj! {
+                "zoom" => id,
+                "scaleId" => scale_id,
+                "min" => min,
+                "max" => max,
+                "mode" => mode,
             },
 
             _ => return Array::new().into(),
@@ -127,7 +152,7 @@ impl From<JsValue> for MessageContent {
         }
 
         destructure!(
-            value => function, args, data, canvas, width, height, obj, id, mutate, plugins, defaults, updated, animate
+            value => function, args, data, canvas, width, height, obj, id, mutate, plugins, defaults, updated, mode, transition
         );
 
         if_let_all!(function, args => {
@@ -157,11 +182,12 @@ impl From<JsValue> for MessageContent {
             });
         }
 
-        if_let_all!(updated, id, animate => {
+        if_let_all!(updated, id, mode, transition => {
             return MessageContent::Update {
                 updated,
                 id: id.as_string().unwrap_or_default(),
-                animate: animate.as_bool().unwrap_or_default(),
+                mode: mode.as_string(),
+                transition,
             };
         });
 
@@ -345,13 +371,36 @@ impl ChartWorker {
         &self,
         updated: JsValue,
         id: &String,
-        animate: bool,
+        mode: &crate::UpdateMode,
+        transition: JsValue,
     ) -> Result<bool, Box<dyn std::error::Error>> {
         self.send(
             MessageContent::Update {
                 updated,
                 id: id.to_string(),
-                animate,
+                mode: mode.as_str().map(Into::into),
+                transition,
+            },
+            &[],
+        )
+        .await
+        .map(|v| v.as_bool().unwrap_or_default())
+    }
+
+    pub(crate) async fn zoom(
+        &self,
+        id: &str,
+        scale_id: Option<String>,
+        (min, max): (JsValue, JsValue),
+        mode: &crate::UpdateMode,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        self.send(
+            MessageContent::Zoom {
+                id: id.to_string(),
+                scale_id,
+                min,
+                max,
+                mode: mode.as_str().map(Into::into),
             },
             &[],
         )